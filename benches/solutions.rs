@@ -0,0 +1,90 @@
+//! Benchmarks every generator and every part, alternates included, through the solution
+//! registry: `cargo bench -- 2022/day03` to narrow it down.
+
+use std::{fs, hint::black_box};
+
+use advent_2022::{
+    gen::{self, Size},
+    params::Params,
+    solution::registry,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+
+/// Roughly the size of a real puzzle input, or larger where that's cheap.
+fn synthetic_size(day: u8) -> Size {
+    let count = match day {
+        11 => 8,
+        14 => 150,
+        15 => 30,
+        16 => 60,
+        _ => 2000,
+    };
+    Size {
+        count,
+        width: 100,
+        height: 100,
+        ..Size::default()
+    }
+}
+
+/// Each input with its parameters: the sample's from `test_input/YYYY/dayNN.params.json`, if any.
+fn inputs(year: u16, day: u8) -> Vec<(&'static str, String, Params)> {
+    let mut inputs = vec![];
+    if let Ok(input) = fs::read_to_string(format!("test_input/{year}/day{day:02}.txt")) {
+        let path = format!("test_input/{year}/day{day:02}.params.json");
+        let params = Params::load(path.as_ref()).unwrap_or_default();
+        inputs.push(("test_input", input, params));
+    }
+    let size = synthetic_size(day);
+    if let Some(input) = gen::generate(year, day, 2022, size) {
+        inputs.push(("synthetic", input, gen::params(year, day, size)));
+    }
+    inputs
+}
+
+fn bench_solutions(c: &mut Criterion) {
+    for ((year, day), solution) in registry() {
+        let mut group = c.benchmark_group(format!("{year}/day{day:02}"));
+        for (label, input, params) in inputs(year, day) {
+            if let Err(err) = solution.parse(&input, None, &params) {
+                eprintln!("skipping day {day} on {label}: {err}");
+                continue;
+            }
+            let generators = std::iter::once(None).chain(solution.parsers().into_iter().map(Some));
+            for name in generators {
+                let id = match name {
+                    Some(name) => format!("generator {name}"),
+                    None => "generator".to_string(),
+                };
+                group.bench_with_input(BenchmarkId::new(id, label), &input, |b, input| {
+                    b.iter(|| solution.parse(black_box(input), name, &params))
+                });
+            }
+
+            for (part, name) in solution.variants() {
+                let Ok(parsed) = solution.parse(&input, name, &params) else {
+                    continue;
+                };
+                if let Err(err) = solution.solve(&*parsed, part, name) {
+                    eprintln!("skipping day {day} part {part} on {label}: {err}");
+                    continue;
+                }
+                let id = match name {
+                    Some(name) => format!("part{part} {name}"),
+                    None => format!("part{part}"),
+                };
+                group.bench_with_input(BenchmarkId::new(id, label), &*parsed, |b, parsed| {
+                    b.iter(|| solution.solve(black_box(parsed), part, name))
+                });
+            }
+        }
+        group.finish();
+    }
+}
+
+criterion_group! {
+    name = benches;
+    config = Criterion::default().sample_size(20);
+    targets = bench_solutions
+}
+criterion_main!(benches);
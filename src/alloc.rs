@@ -0,0 +1,146 @@
+//! Allocation counting for memory profiling. With the `count-alloc` feature the crate installs
+//! [`Counting`] as the global allocator and [`measure`] reports what a closure allocated;
+//! without it, [`measure`] returns `None` and allocation costs nothing extra.
+//!
+//! The counters are process-wide, so a measurement includes whatever every thread allocated
+//! meanwhile: the worker threads of a solver using rayon, which is what it should count, but also
+//! anything else running at the time. `advent run` and `advent report` run one solver at a time
+//! for that reason, and `advent all`, which runs them all at once, doesn't measure.
+
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    fmt,
+    sync::atomic::{AtomicU64, AtomicUsize, Ordering},
+};
+
+static COUNT: AtomicU64 = AtomicU64::new(0);
+static BYTES: AtomicU64 = AtomicU64::new(0);
+static LIVE: AtomicUsize = AtomicUsize::new(0);
+static PEAK: AtomicUsize = AtomicUsize::new(0);
+
+/// The system allocator, counting allocations, bytes allocated and the most bytes live at once.
+pub struct Counting;
+
+fn grow(size: usize) {
+    BYTES.fetch_add(size as u64, Ordering::Relaxed);
+    let live = LIVE.fetch_add(size, Ordering::Relaxed) + size;
+    PEAK.fetch_max(live, Ordering::Relaxed);
+}
+
+fn shrink(size: usize) {
+    LIVE.fetch_sub(size, Ordering::Relaxed);
+}
+
+unsafe impl GlobalAlloc for Counting {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            grow(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        shrink(layout.size());
+    }
+
+    // A `Vec` growing is counted as an allocation of the extra bytes
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            COUNT.fetch_add(1, Ordering::Relaxed);
+            if new_size > layout.size() {
+                grow(new_size - layout.size());
+            } else {
+                shrink(layout.size() - new_size);
+            }
+        }
+        new_ptr
+    }
+}
+
+#[cfg(feature = "count-alloc")]
+#[global_allocator]
+static GLOBAL: Counting = Counting;
+
+/// What one generator or part allocated.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct Usage {
+    /// Allocations, reallocations included.
+    pub count: u64,
+    /// Bytes allocated in total, whether or not they were freed again.
+    pub bytes: u64,
+    /// Most bytes live at once, above what was live at the start.
+    pub peak: usize,
+}
+
+impl fmt::Display for Usage {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} allocations, {} bytes, {} bytes peak",
+            self.count, self.bytes, self.peak
+        )
+    }
+}
+
+/// Whether allocations are being counted, i.e. the crate was built with `count-alloc`.
+pub const fn enabled() -> bool {
+    cfg!(feature = "count-alloc")
+}
+
+/// Run `f`, along with what it allocated if allocations are being counted. Measurements can't be
+/// nested, as each one resets the peak.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if !enabled() {
+        return (f(), None);
+    }
+    let count = COUNT.load(Ordering::Relaxed);
+    let bytes = BYTES.load(Ordering::Relaxed);
+    let live = LIVE.load(Ordering::Relaxed);
+    PEAK.store(live, Ordering::Relaxed);
+
+    let result = f();
+
+    let usage = Usage {
+        count: COUNT.load(Ordering::Relaxed) - count,
+        bytes: BYTES.load(Ordering::Relaxed) - bytes,
+        peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+    };
+    (result, Some(usage))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_measure() {
+        let (len, usage) = measure(|| {
+            let big = vec![1u8; 1 << 20];
+            drop(big);
+            vec![0u32; 16].len()
+        });
+        assert_eq!(len, 16);
+        if enabled() {
+            // other tests allocate on other threads, so only lower bounds hold
+            let usage = usage.unwrap();
+            assert!(usage.count >= 2);
+            assert!(usage.bytes >= (1 << 20) + 64);
+            assert!(usage.peak >= 1 << 20);
+        } else {
+            assert_eq!(usage, None);
+        }
+    }
+}
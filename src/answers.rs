@@ -0,0 +1,288 @@
+use std::{
+    fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::{
+    error::Error,
+    runner::{self, Solver},
+    solution::Answer,
+    util::parse::normalize,
+};
+
+/// Known answers for one day's real input, stored as `answers/YYYY/dayNN.json`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Answers {
+    /// SHA-256 of the normalized input the answers belong to. `None` until an input is recorded.
+    pub input_sha256: Option<String>,
+    #[serde(default)]
+    pub part1: Expected,
+    #[serde(default)]
+    pub part2: Expected,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Expected {
+    pub answer: Option<String>,
+    /// Answers the puzzle site rejected, so a regression back to one is called out.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub wrong: Vec<String>,
+}
+
+impl Answers {
+    pub fn path(dir: &Path, day: u8) -> PathBuf {
+        dir.join(format!("day{day:02}.json"))
+    }
+
+    /// The stored answers for `day`, or `None` if there is no file for it yet.
+    pub fn load(dir: &Path, day: u8) -> io::Result<Option<Answers>> {
+        let json = match fs::read_to_string(Answers::path(dir, day)) {
+            Ok(json) => json,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(err) => return Err(err),
+        };
+        serde_json::from_str(&json)
+            .map(Some)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn save(&self, dir: &Path, day: u8) -> io::Result<()> {
+        fs::create_dir_all(dir)?;
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(Answers::path(dir, day), json + "\n")
+    }
+
+    pub fn part(&self, part: u8) -> &Expected {
+        match part {
+            1 => &self.part1,
+            _ => &self.part2,
+        }
+    }
+
+    pub fn part_mut(&mut self, part: u8) -> &mut Expected {
+        match part {
+            1 => &mut self.part1,
+            _ => &mut self.part2,
+        }
+    }
+}
+
+/// Hex SHA-256 of `input` after normalizing, so line endings don't change the hash.
+pub fn input_hash(input: &str) -> String {
+    Sha256::digest(normalize(input).as_bytes())
+        .iter()
+        .map(|byte| format!("{byte:02x}"))
+        .collect()
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Outcome {
+    Match,
+    Mismatch {
+        expected: String,
+        actual: Answer,
+    },
+    /// The solver produced an answer that is known to be wrong.
+    KnownWrong(Answer),
+    /// Nothing is stored for this part yet.
+    Unknown(Answer),
+    Failed(Error),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Check {
+    pub solver: Solver,
+    pub outcome: Outcome,
+}
+
+impl Check {
+    pub fn passed(&self) -> bool {
+        matches!(self.outcome, Outcome::Match | Outcome::Unknown(_))
+    }
+}
+
+/// Why a day's answers could not be checked.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Skipped {
+    NoAnswers,
+    NoInput,
+    InputChanged { expected: String, actual: String },
+}
+
+impl fmt::Display for Skipped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Skipped::NoAnswers => write!(f, "no stored answers"),
+            Skipped::NoInput => write!(f, "no input"),
+            Skipped::InputChanged { expected, actual } => write!(
+                f,
+                "input hash {actual} does not match the stored {expected}"
+            ),
+        }
+    }
+}
+
+/// Run every registered solver for `day` of `year`, default and alternates, and compare with
+/// `answers`. The input hash must match the stored one, if any.
+pub fn verify(year: u16, day: u8, input: &str, answers: &Answers) -> Result<Vec<Check>, Skipped> {
+    let hash = input_hash(input);
+    if let Some(expected) = &answers.input_sha256 {
+        if *expected != hash {
+            return Err(Skipped::InputChanged {
+                expected: expected.clone(),
+                actual: hash,
+            });
+        }
+    }
+
+    let checks = runner::solvers()
+        .into_iter()
+        .filter(|solver| (solver.year, solver.day) == (year, day))
+        .map(|solver| {
+            let expected = answers.part(solver.part);
+            let outcome = match solver.run(input) {
+                Err(err) => Outcome::Failed(err),
+                Ok(run) => {
+                    let actual = run.answer.to_string();
+                    match &expected.answer {
+                        Some(answer) if *answer == actual => Outcome::Match,
+                        _ if expected.wrong.contains(&actual) => Outcome::KnownWrong(run.answer),
+                        Some(answer) => Outcome::Mismatch {
+                            expected: answer.clone(),
+                            actual: run.answer,
+                        },
+                        None => Outcome::Unknown(run.answer),
+                    }
+                }
+            };
+            Check { solver, outcome }
+        })
+        .collect();
+    Ok(checks)
+}
+
+/// Store the default solvers' answers for `input`. A stored answer that differs is only replaced
+/// when `force` is set. Fails without touching `answers` if `day` has no solver.
+pub fn record(
+    year: u16,
+    day: u8,
+    input: &str,
+    answers: &mut Answers,
+    force: bool,
+) -> Result<(), Error> {
+    let hash = input_hash(input);
+    let mut recorded = answers.clone();
+    recorded.input_sha256 = Some(hash);
+    for part in [1, 2] {
+        let Some(solver) = runner::find(year, day, part, None) else {
+            return Err(Error::UnknownSolver {
+                day,
+                part,
+                name: None,
+            });
+        };
+        let actual = match solver.run(input) {
+            Ok(run) => run.answer.to_string(),
+            Err(Error::Unimplemented { .. }) => continue,
+            Err(err) => return Err(err),
+        };
+        let expected = recorded.part_mut(part);
+        match &expected.answer {
+            Some(answer) if *answer != actual && !force => {
+                return Err(Error::no_solution(
+                    day,
+                    format!("part {part} is stored as {answer:?} but the solver gives {actual:?}"),
+                ))
+            }
+            _ => expected.answer = Some(actual),
+        }
+    }
+    *answers = recorded;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY02: &str = "A Y\nB X\nC Z\n";
+
+    #[test]
+    fn test_input_hash() {
+        assert_eq!(
+            input_hash("abc"),
+            "ba7816bf8f01cfea414140de5dae2223b00361a396177a9cb410ff61f20015ad"
+        );
+        assert_eq!(input_hash(DAY02), input_hash("A Y\r\nB X\r\nC Z\r\n"));
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers = Answers {
+            input_sha256: Some(input_hash(DAY02)),
+            part1: Expected {
+                answer: Some("15".to_string()),
+                wrong: vec![],
+            },
+            part2: Expected {
+                answer: None,
+                wrong: vec!["12".to_string()],
+            },
+        };
+        let checks = verify(2022, 2, DAY02, &answers).unwrap();
+        assert_eq!(checks.len(), 2);
+        assert_eq!(checks[0].outcome, Outcome::Match);
+        assert_eq!(checks[1].outcome, Outcome::KnownWrong(Answer::from(12u32)));
+
+        let mut changed = answers.clone();
+        changed.part1.answer = Some("16".to_string());
+        let checks = verify(2022, 2, DAY02, &changed).unwrap();
+        assert!(!checks[0].passed());
+
+        assert!(matches!(
+            verify(2022, 2, "A X\n", &answers),
+            Err(Skipped::InputChanged { .. })
+        ));
+    }
+
+    #[test]
+    fn test_record() {
+        let mut answers = Answers::default();
+        record(2022, 2, DAY02, &mut answers, false).unwrap();
+        assert_eq!(answers.input_sha256, Some(input_hash(DAY02)));
+        assert_eq!(answers.part2.answer.as_deref(), Some("12"));
+
+        answers.part1.answer = Some("16".to_string());
+        assert!(record(2022, 2, DAY02, &mut answers, false).is_err());
+        assert_eq!(answers.part1.answer.as_deref(), Some("16"));
+        record(2022, 2, DAY02, &mut answers, true).unwrap();
+        assert_eq!(answers.part1.answer.as_deref(), Some("15"));
+
+        let mut answers = Answers::default();
+        assert_eq!(
+            record(2022, 42, DAY02, &mut answers, false),
+            Err(Error::UnknownSolver {
+                day: 42,
+                part: 1,
+                name: None
+            })
+        );
+        assert_eq!(answers, Answers::default());
+    }
+
+    #[test]
+    fn test_load_and_save() {
+        let dir = std::env::temp_dir().join(format!("advent-answers-{}", std::process::id()));
+        assert_eq!(Answers::load(&dir, 2).unwrap(), None);
+
+        let mut answers = Answers::default();
+        answers.part1.wrong.push("418".to_string());
+        answers.save(&dir, 2).unwrap();
+        assert!(dir.join("day02.json").exists());
+        assert_eq!(Answers::load(&dir, 2).unwrap(), Some(answers));
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,927 @@
+use std::{
+    collections::BTreeMap,
+    fs,
+    io::{self, Read, Write},
+    path::{Path, PathBuf},
+    process::ExitCode,
+    time::{Duration, Instant},
+};
+
+use advent_2022::{
+    alloc::Usage,
+    answers::{self, Answers, Outcome, Skipped},
+    differential, error,
+    fetch::Fetcher,
+    gen::{self, Size},
+    params::Params,
+    report::{self, Report, Thresholds},
+    runner::{self, Run, Solver},
+    scaffold::{self, Stub},
+    viz::{self, Format},
+    y2022::{self, day02::Column},
+};
+use clap::{Args, Parser, Subcommand};
+use itertools::Itertools;
+use tracing_subscriber::{fmt::format::FmtSpan, EnvFilter};
+
+/// Run Advent of Code solutions without cargo-aoc.
+#[derive(Parser)]
+#[command(name = "advent")]
+struct Cli {
+    /// Event year. Commands for a single day default to the latest year, the others cover every
+    /// year when omitted
+    #[arg(long, global = true)]
+    year: Option<u16>,
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// List every registered year, day, part and alternate solver
+    List,
+    /// Run a day, e.g. `advent run day3 part1 HashSet` or `advent run --day 3 --part 1`
+    Run {
+        #[command(flatten)]
+        target: Target,
+        /// Input file, or `-` to read from stdin. Defaults to `input/YYYY/dayNN.txt`, downloaded if missing
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Puzzle parameter, e.g. `--param row=10`. Takes precedence over `--params`
+        #[arg(short, long = "param", value_name = "KEY=VALUE")]
+        param: Vec<String>,
+        /// JSON file of puzzle parameters, e.g. `test_input/2022/day15.params.json`
+        #[arg(long)]
+        params: Option<PathBuf>,
+        #[command(flatten)]
+        viz: VizArgs,
+    },
+    /// Run both parts of every day with an input in `input/YYYY/`, all days at once
+    All,
+    /// Run every day with an input in `input/YYYY/`, one solver at a time, and write a JSON
+    /// report of the answers, timings, memory and input hashes
+    Report {
+        /// Include alternate solvers
+        #[arg(long)]
+        variants: bool,
+        /// Write the report here instead of stdout
+        #[arg(short, long)]
+        out: Option<PathBuf>,
+    },
+    /// Compare two reports, e.g. from before and after a change. Fails on regressions
+    Compare {
+        old: PathBuf,
+        new: PathBuf,
+        /// Relative change in time or peak allocation worth reporting, in percent
+        #[arg(long, default_value_t = 20.0)]
+        threshold: f64,
+        /// Time changes below this many milliseconds are ignored as noise
+        #[arg(long, default_value_t = 1.0)]
+        min_ms: f64,
+    },
+    /// Check every solver, alternates included, against the answers stored in `answers/`
+    Verify {
+        /// Only verify this day
+        #[arg(value_parser = parse_day)]
+        day: Option<u8>,
+    },
+    /// Store the answers for a day's input in `answers/YYYY/dayNN.json`
+    Record {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        /// Input file, or `-` to read from stdin. Defaults to `input/YYYY/dayNN.txt`, downloaded if missing
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Replace stored answers that differ from the solver's
+        #[arg(long)]
+        force: bool,
+    },
+    /// Compare every pair of variants for the same part on random and mutated inputs
+    Diff {
+        /// Only check this day
+        #[arg(value_parser = parse_day)]
+        day: Option<u8>,
+        /// Number of seeds to generate inputs from
+        #[arg(long, default_value_t = 100)]
+        seeds: u64,
+        /// Mutated copies of each generated input to try
+        #[arg(long, default_value_t = 10)]
+        mutations: usize,
+        #[command(flatten)]
+        size: SizeArgs,
+    },
+    /// Print what a day's generator parsed from its input, as JSON
+    DumpParsed {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        /// Name of an alternate generator, e.g. `rayon`
+        name: Option<String>,
+        /// Input file, or `-` to read from stdin. Defaults to `input/YYYY/dayNN.txt`, downloaded if missing
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Puzzle parameter, e.g. `--param row=10`. Takes precedence over `--params`
+        #[arg(short, long = "param", value_name = "KEY=VALUE")]
+        param: Vec<String>,
+        /// JSON file of puzzle parameters, e.g. `test_input/2022/day15.params.json`
+        #[arg(long)]
+        params: Option<PathBuf>,
+    },
+    /// Start a new day from `src/template.rs`, registered and with a sample input for its tests
+    Scaffold {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        /// The puzzle's sample input, or `-` to read it from stdin. Left empty when omitted
+        #[arg(short, long)]
+        sample: Option<PathBuf>,
+        /// Expected part 1 answer for the sample
+        #[arg(long)]
+        part1: Option<String>,
+        /// Expected part 2 answer for the sample
+        #[arg(long)]
+        part2: Option<String>,
+    },
+    /// Download inputs into `input/YYYY/` using the session token in `ADVENT_SESSION`. Cached
+    /// inputs are checked against the hash recorded when they were downloaded
+    Fetch {
+        /// Days to fetch. Every registered day when omitted
+        #[arg(value_parser = parse_day)]
+        days: Vec<u8>,
+    },
+    /// List what the letters of an input could mean, like the second column of day 2's strategy
+    /// guide, with the score each reading gives
+    Cipher {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        /// Input file, or `-` to read from stdin. Defaults to `input/YYYY/dayNN.txt`, downloaded if missing
+        #[arg(short, long)]
+        input: Option<PathBuf>,
+        /// Only readings with exactly this total score
+        #[arg(long)]
+        target: Option<u64>,
+        /// Only readings scoring at least this
+        #[arg(long)]
+        min: Option<u64>,
+        /// Only readings scoring at most this
+        #[arg(long)]
+        max: Option<u64>,
+        /// A letter whose meaning is known, e.g. `--fix X=rock` or `--fix Z=win`
+        #[arg(long, value_name = "LETTER=MEANING")]
+        fix: Vec<String>,
+        /// Rule parameter, e.g. `--param payoff=...`. Takes precedence over `--params`
+        #[arg(short, long = "param", value_name = "KEY=VALUE")]
+        param: Vec<String>,
+        /// JSON file of the game's rules, e.g. `test_input/2022/day02.rpsls.json`
+        #[arg(long)]
+        params: Option<PathBuf>,
+        /// Print the readings as JSON
+        #[arg(long)]
+        json: bool,
+    },
+    /// Print a random but valid input for a day
+    Gen {
+        #[arg(value_parser = parse_day)]
+        day: u8,
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+        #[command(flatten)]
+        size: SizeArgs,
+    },
+}
+
+/// Which solver to run, given either as positionals or as flags
+#[derive(Args)]
+struct Target {
+    /// Day number, e.g. `3` or `day3`
+    #[arg(value_parser = parse_day, required_unless_present = "day_flag")]
+    day: Option<u8>,
+    /// Part number, e.g. `1` or `part1`. Runs both parts when omitted
+    #[arg(value_parser = parse_part)]
+    part: Option<u8>,
+    /// Name of an alternate solver, e.g. `HashSet` or `chunks`
+    name: Option<String>,
+    /// Day number, instead of the positional one
+    #[arg(long = "day", value_parser = parse_day, conflicts_with = "day")]
+    day_flag: Option<u8>,
+    /// Part number, instead of the positional one
+    #[arg(long = "part", value_parser = parse_part, conflicts_with = "part")]
+    part_flag: Option<u8>,
+    /// Alternate solver name, instead of the positional one
+    #[arg(long = "name", conflicts_with = "name")]
+    name_flag: Option<String>,
+}
+
+impl Target {
+    fn resolve(self) -> (u8, Option<u8>, Option<String>) {
+        let day = self.day.or(self.day_flag).expect("clap requires a day");
+        (
+            day,
+            self.part.or(self.part_flag),
+            self.name.or(self.name_flag),
+        )
+    }
+}
+
+/// Size of a generated input, see `advent_2022::gen::Size`
+#[derive(Args)]
+struct SizeArgs {
+    /// Number of records: elves, rounds, moves, monkeys, sensors, valves, ...
+    #[arg(long, default_value_t = Size::default().count)]
+    count: usize,
+    /// Grid width for days 8 and 12
+    #[arg(long, default_value_t = Size::default().width)]
+    width: usize,
+    /// Grid height for days 8 and 12
+    #[arg(long, default_value_t = Size::default().height)]
+    height: usize,
+    /// Day 15's search area. Solve with `-p bound=AREA -p row=AREA/2` if changed
+    #[arg(long, default_value_t = Size::default().area)]
+    area: usize,
+}
+
+impl From<SizeArgs> for Size {
+    fn from(args: SizeArgs) -> Self {
+        Size {
+            count: args.count,
+            width: args.width,
+            height: args.height,
+            area: args.area,
+        }
+    }
+}
+
+const ANSWERS_DIR: &str = "answers";
+
+fn parse_day(value: &str) -> Result<u8, String> {
+    let day = value.strip_prefix("day").unwrap_or(value);
+    day.parse()
+        .map_err(|_| format!("invalid day `{value}`, expected e.g. `3` or `day3`"))
+}
+
+fn parse_part(value: &str) -> Result<u8, String> {
+    let part = value.strip_prefix("part").unwrap_or(value);
+    match part.parse() {
+        Ok(part @ (1 | 2)) => Ok(part),
+        _ => Err(format!(
+            "invalid part `{value}`, expected `1`, `2`, `part1` or `part2`"
+        )),
+    }
+}
+
+/// The year a single-day command works on: `--year`, or the latest year with solutions.
+fn single_year(year: Option<u16>) -> u16 {
+    year.or_else(|| runner::years().last().copied())
+        .unwrap_or(y2022::YEAR)
+}
+
+/// The years a command over every day works on: `--year`, or all of them.
+fn years(year: Option<u16>) -> Vec<u16> {
+    year.map_or_else(runner::years, |year| vec![year])
+}
+
+/// Every registered day of the years from `years`.
+fn all_days(year: Option<u16>) -> Vec<(u16, u8)> {
+    years(year)
+        .into_iter()
+        .flat_map(|year| runner::days(year).into_iter().map(move |day| (year, day)))
+        .collect()
+}
+
+fn read_input(year: u16, day: u8, path: Option<PathBuf>) -> io::Result<String> {
+    match path {
+        Some(path) if path.as_os_str() == "-" => {
+            let mut input = String::new();
+            io::stdin().read_to_string(&mut input)?;
+            Ok(input)
+        }
+        Some(path) => fs::read_to_string(path),
+        None => Fetcher::from_env(year).get(day).map_err(io::Error::other),
+    }
+}
+
+#[derive(Args)]
+struct VizArgs {
+    /// Also draw the puzzle, as `ascii`, `ppm`, `png` or `gif`
+    #[arg(long, value_name = "FORMAT")]
+    viz: Option<Format>,
+    /// Where to write the drawing. Defaults to stdout for `ascii` and `viz/YYYY/dayNN.<ext>` otherwise
+    #[arg(long, value_name = "PATH")]
+    viz_out: Option<PathBuf>,
+    /// Pixels per cell in images
+    #[arg(long, default_value_t = 4)]
+    scale: usize,
+    /// Hundredths of a second per GIF frame
+    #[arg(long, default_value_t = 5)]
+    delay: u16,
+}
+
+fn visualize(year: u16, day: u8, input: &str, params: &Params, args: VizArgs) -> bool {
+    let Some(format) = args.viz else {
+        return true;
+    };
+    let frames = match runner::render(year, day, input, params) {
+        Ok(Some(frames)) => frames,
+        Ok(None) => {
+            eprintln!("Day {day} has no visualization");
+            return false;
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+    let path = match (args.viz_out, format) {
+        (Some(path), _) => Some(path),
+        (None, Format::Ascii) => None,
+        (None, format) => Some(PathBuf::from(format!(
+            "viz/{year}/day{day:02}.{}",
+            format.extension()
+        ))),
+    };
+    let written = match &path {
+        Some(path) => path
+            .parent()
+            .filter(|dir| !dir.as_os_str().is_empty())
+            .map_or(Ok(()), fs::create_dir_all)
+            .and_then(|_| fs::File::create(path))
+            .map(io::BufWriter::new)
+            .and_then(|mut out| {
+                viz::write(&mut out, &frames, format, args.scale, args.delay)?;
+                out.flush()
+            }),
+        None => viz::write(
+            &mut io::stdout().lock(),
+            &frames,
+            format,
+            args.scale,
+            args.delay,
+        ),
+    };
+    match (written, path) {
+        (Ok(()), Some(path)) => {
+            println!("Wrote {}", path.display());
+            true
+        }
+        (Ok(()), None) => true,
+        (Err(err), _) => {
+            eprintln!("Error writing visualization for day {day}: {err}");
+            false
+        }
+    }
+}
+
+fn read_params(path: Option<PathBuf>, flags: &[String]) -> Result<Params, String> {
+    let mut params = match path {
+        Some(path) => Params::load(&path)
+            .map_err(|err| format!("Error reading parameters {}: {err}", path.display()))?,
+        None => Params::default(),
+    };
+    for flag in flags {
+        params.set_flag(flag)?;
+    }
+    Ok(params)
+}
+
+fn list(years: &[u16]) {
+    for solver in runner::solvers() {
+        if !years.contains(&solver.year) {
+            continue;
+        }
+        let (year, day, part) = (solver.year, solver.day, solver.part);
+        match solver.name {
+            Some(name) => println!("{year} day{day} part{part} {name}"),
+            None => println!("{year} day{day} part{part}"),
+        }
+    }
+    for (year, day, name) in runner::generators() {
+        if years.contains(&year) {
+            println!("{year} day{day} generator {name}");
+        }
+    }
+}
+
+fn report(solver: &Solver, result: error::Result<Run>) -> bool {
+    let name = solver
+        .name
+        .map(|name| format!(" - {name}"))
+        .unwrap_or_default();
+    match result {
+        Ok(run) => {
+            println!(
+                "{} Day {} - Part {}{name} : {}",
+                solver.year, solver.day, solver.part, run.answer
+            );
+            let alloc =
+                |usage: Option<Usage>| usage.map(|usage| format!(" ({usage})")).unwrap_or_default();
+            println!(
+                "\tgenerator: {:?}{},",
+                run.parse_time,
+                alloc(run.parse_alloc)
+            );
+            println!("\trunner: {:?}{}\n", run.solve_time, alloc(run.solve_alloc));
+            true
+        }
+        Err(err) => {
+            eprintln!(
+                "{} Day {} - Part {}{name} : {err}\n",
+                solver.year, solver.day, solver.part
+            );
+            false
+        }
+    }
+}
+
+/// Every day's input in `input/YYYY/` for `years`, noting the days without one on stderr.
+fn read_inputs(years: &[u16]) -> BTreeMap<(u16, u8), String> {
+    let mut inputs = BTreeMap::new();
+    for &year in years {
+        for day in runner::days(year) {
+            match read_input(year, day, None) {
+                Ok(input) => {
+                    inputs.insert((year, day), input);
+                }
+                Err(err) => eprintln!("{year} Day {day} : skipped, {err}\n"),
+            }
+        }
+    }
+    inputs
+}
+
+fn run_all(years: &[u16]) -> bool {
+    let inputs = read_inputs(years);
+    let start = Instant::now();
+    let results = runner::run_all(&inputs);
+    let elapsed = start.elapsed();
+    let mut success = true;
+    for (solver, result) in results {
+        success &= report(&solver, result);
+    }
+    println!("{} days in {elapsed:?}", inputs.len());
+    success
+}
+
+fn write_report(years: &[u16], variants: bool, out: Option<PathBuf>) -> bool {
+    let report = Report::generate(&read_inputs(years), variants);
+    let json = report.to_json();
+    match out {
+        Some(path) => match fs::write(&path, json + "\n") {
+            Ok(()) => {
+                eprintln!(
+                    "Wrote {} entries to {}",
+                    report.entries.len(),
+                    path.display()
+                );
+                true
+            }
+            Err(err) => {
+                eprintln!("Error writing {}: {err}", path.display());
+                false
+            }
+        },
+        None => {
+            println!("{json}");
+            true
+        }
+    }
+}
+
+fn compare(old: &Path, new: &Path, thresholds: Thresholds) -> bool {
+    let load = |path: &Path| {
+        Report::load(path).map_err(|err| eprintln!("Error reading {}: {err}", path.display()))
+    };
+    let (Ok(old), Ok(new)) = (load(old), load(new)) else {
+        return false;
+    };
+    let changes = report::compare(&old, &new, thresholds);
+    if changes.is_empty() {
+        println!("No changes");
+    }
+    for change in &changes {
+        let mark = if change.is_regression() { "!" } else { " " };
+        println!("{mark} {change}");
+    }
+    !changes.iter().any(|change| change.is_regression())
+}
+
+fn answers_dir(year: u16) -> PathBuf {
+    Path::new(ANSWERS_DIR).join(year.to_string())
+}
+
+fn verify(days: Vec<(u16, u8)>) -> bool {
+    let mut success = true;
+    for (year, day) in days {
+        let answers = match Answers::load(&answers_dir(year), day) {
+            Ok(Some(answers)) => Ok(answers),
+            Ok(None) => Err(Skipped::NoAnswers),
+            Err(err) => {
+                eprintln!("{year} day{day}: error reading stored answers: {err}");
+                success = false;
+                continue;
+            }
+        };
+        let checks = answers.and_then(|answers| {
+            let input = read_input(year, day, None).map_err(|_| Skipped::NoInput)?;
+            answers::verify(year, day, &input, &answers)
+        });
+        let checks = match checks {
+            Ok(checks) => checks,
+            Err(skipped @ Skipped::InputChanged { .. }) => {
+                eprintln!("{year} day{day}: {skipped}");
+                success = false;
+                continue;
+            }
+            Err(skipped) => {
+                println!("{year} day{day}: skipped, {skipped}");
+                continue;
+            }
+        };
+
+        for check in checks {
+            let solver = check.solver;
+            let name = solver
+                .name
+                .map(|name| format!(" {name}"))
+                .unwrap_or_default();
+            let label = format!("{year} day{} part{}{name}", solver.day, solver.part);
+            success &= check.passed();
+            match check.outcome {
+                Outcome::Match => println!("{label}: ok"),
+                Outcome::Unknown(answer) => {
+                    println!("{label}: no stored answer, got {:?}", answer.to_string())
+                }
+                Outcome::Mismatch { expected, actual } => eprintln!(
+                    "{label}: MISMATCH, expected {expected:?} but got {:?}",
+                    actual.to_string()
+                ),
+                Outcome::KnownWrong(answer) => {
+                    eprintln!("{label}: known wrong answer {:?}", answer.to_string())
+                }
+                Outcome::Failed(err) => eprintln!("{label}: {err}"),
+            }
+        }
+    }
+    success
+}
+
+fn record(year: u16, day: u8, input: &str, force: bool) -> bool {
+    let dir = answers_dir(year);
+    let result = Answers::load(&dir, day)
+        .map_err(|err| err.to_string())
+        .and_then(|answers| {
+            let mut answers = answers.unwrap_or_default();
+            answers::record(year, day, input, &mut answers, force)
+                .map_err(|err| err.to_string())?;
+            answers.save(&dir, day).map_err(|err| err.to_string())
+        });
+    match result {
+        Ok(()) => {
+            println!("Recorded {}", Answers::path(&dir, day).display());
+            true
+        }
+        Err(err) => {
+            eprintln!("Error recording answers for day {day}: {err}");
+            false
+        }
+    }
+}
+
+/// The readings of `day`'s input that satisfy `constraints`, as text or JSON.
+fn cipher(
+    year: u16,
+    day: u8,
+    input: &str,
+    params: &Params,
+    constraints: &Params,
+    json: bool,
+) -> bool {
+    let readings = match runner::decipher(year, day, input, params, constraints) {
+        Ok(Some(readings)) => readings,
+        Ok(None) => {
+            eprintln!("The input of day {day} of {year} only has the one reading");
+            return false;
+        }
+        Err(err) => {
+            eprintln!("{err}");
+            return false;
+        }
+    };
+    if json {
+        println!("{:#}", serde_json::json!(readings));
+        return true;
+    }
+    for reading in &readings {
+        let mapping = reading
+            .mapping
+            .iter()
+            .map(|(letter, meaning)| format!("{letter}={meaning}"))
+            .join(" ");
+        let kind = match reading.column {
+            Column::Moves => "moves",
+            Column::Outcomes => "outcomes",
+        };
+        println!("{kind:<8} {mapping:<40} {}", reading.score);
+    }
+    println!("{} readings match", readings.len());
+    true
+}
+
+/// Log spans and events to stderr when `ADVENT_LOG` is set, e.g. `ADVENT_LOG=debug`.
+fn init_tracing() {
+    if std::env::var_os("ADVENT_LOG").is_none() {
+        return;
+    }
+    tracing_subscriber::fmt()
+        .with_env_filter(EnvFilter::from_env("ADVENT_LOG"))
+        .with_span_events(FmtSpan::CLOSE)
+        .with_writer(io::stderr)
+        .init();
+}
+
+fn main() -> ExitCode {
+    init_tracing();
+    let cli = Cli::parse();
+    let year = cli.year;
+    match cli.command {
+        Command::List => list(&years(year)),
+        Command::Run {
+            target,
+            input,
+            param,
+            params,
+            viz,
+        } => {
+            let year = single_year(year);
+            let (day, part, name) = target.resolve();
+            let solvers = match part {
+                Some(part) => runner::find(year, day, part, name.as_deref())
+                    .into_iter()
+                    .collect_vec(),
+                None => [1, 2]
+                    .into_iter()
+                    .filter_map(|part| runner::find(year, day, part, name.as_deref()))
+                    .collect_vec(),
+            };
+            if solvers.is_empty() {
+                eprintln!("No solver registered for that year/day/part/name, see `advent list`");
+                return ExitCode::FAILURE;
+            }
+
+            let input = match read_input(year, day, input) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("Error reading input for day {day}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let params = match read_params(params, &param) {
+                Ok(params) => params,
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let mut success = true;
+            for solver in solvers {
+                success &= report(&solver, solver.run_with(&input, &params));
+            }
+            success &= visualize(year, day, &input, &params, viz);
+            if !success {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::All => {
+            if !run_all(&years(year)) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Report { variants, out } => {
+            if !write_report(&years(year), variants, out) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Compare {
+            old,
+            new,
+            threshold,
+            min_ms,
+        } => {
+            let thresholds = Thresholds {
+                ratio: threshold / 100.0,
+                min_time: Duration::from_secs_f64(min_ms / 1000.0),
+            };
+            if !compare(&old, &new, thresholds) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Verify { day } => {
+            let days = match day {
+                Some(day) => vec![(single_year(year), day)],
+                None => all_days(year),
+            };
+            if !verify(days) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Record { day, input, force } => {
+            let year = single_year(year);
+            if !runner::days(year).contains(&day) {
+                eprintln!("No solver registered for day {day} of {year}, see `advent list`");
+                return ExitCode::FAILURE;
+            }
+            let input = match read_input(year, day, input) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("Error reading input for day {day}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            if !record(year, day, &input, force) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Diff {
+            day,
+            seeds,
+            mutations,
+            size,
+        } => {
+            let options = differential::Options {
+                seeds: 0..seeds,
+                mutations,
+                size: size.into(),
+            };
+            let days = match day {
+                Some(day) => {
+                    let year = single_year(year);
+                    if !runner::days(year).contains(&day) {
+                        eprintln!(
+                            "No solver registered for day {day} of {year}, see `advent list`"
+                        );
+                        return ExitCode::FAILURE;
+                    }
+                    vec![(year, day)]
+                }
+                None => all_days(year),
+            };
+            for (year, day) in days {
+                if let Some(disagreement) = differential::check_day(year, day, &options) {
+                    eprint!("{disagreement}");
+                    return ExitCode::FAILURE;
+                }
+            }
+            println!("All variants agree");
+        }
+        Command::DumpParsed {
+            day,
+            name,
+            input,
+            param,
+            params,
+        } => {
+            let year = single_year(year);
+            if !runner::days(year).contains(&day) {
+                eprintln!("No solver registered for day {day} of {year}, see `advent list`");
+                return ExitCode::FAILURE;
+            }
+            let input = match read_input(year, day, input) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("Error reading input for day {day}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let dumped = read_params(params, &param).and_then(|params| {
+                runner::dump(year, day, &input, name.as_deref(), &params)
+                    .map_err(|err| err.to_string())
+            });
+            match dumped {
+                Ok(json) => println!("{json:#}"),
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Scaffold {
+            day,
+            sample,
+            part1,
+            part2,
+        } => {
+            let year = single_year(year);
+            let sample = match sample {
+                Some(path) => match read_input(year, day, Some(path)) {
+                    Ok(sample) => sample,
+                    Err(err) => {
+                        eprintln!("Error reading sample for day {day}: {err}");
+                        return ExitCode::FAILURE;
+                    }
+                },
+                None => String::new(),
+            };
+            let stub = Stub {
+                sample,
+                part1,
+                part2,
+            };
+            match scaffold::scaffold(Path::new("."), year, day, &stub) {
+                Ok(written) => {
+                    for path in written {
+                        println!("Wrote {}", path.display());
+                    }
+                }
+                Err(err) => {
+                    eprintln!("Error scaffolding day {day}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+        Command::Fetch { days } => {
+            let days = if days.is_empty() {
+                all_days(year)
+            } else {
+                let year = single_year(year);
+                days.into_iter().map(|day| (year, day)).collect_vec()
+            };
+            let mut success = true;
+            for (year, day) in days {
+                let fetcher = Fetcher::from_env(year);
+                match fetcher.get(day) {
+                    Ok(_) => println!("{year} day{day}: {}", fetcher.path(day).display()),
+                    Err(err) => {
+                        eprintln!("{year} day{day}: {err}");
+                        success = false;
+                    }
+                }
+            }
+            if !success {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Cipher {
+            day,
+            input,
+            target,
+            min,
+            max,
+            fix,
+            param,
+            params,
+            json,
+        } => {
+            let year = single_year(year);
+            if !runner::days(year).contains(&day) {
+                eprintln!("No solver registered for day {day} of {year}, see `advent list`");
+                return ExitCode::FAILURE;
+            }
+            let fixed = fix
+                .iter()
+                .map(|fix| {
+                    let (letter, meaning) = fix.split_once('=').ok_or_else(|| {
+                        format!("invalid letter meaning `{fix}`, expected `LETTER=MEANING`")
+                    })?;
+                    Ok((letter.trim().to_string(), meaning.trim().into()))
+                })
+                .collect::<Result<serde_json::Map<_, _>, String>>();
+            let mut constraints = Params::default();
+            match fixed {
+                Ok(fixed) => constraints.set("fixed", fixed),
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            }
+            for (key, bound) in [("target", target), ("min", min), ("max", max)] {
+                if let Some(bound) = bound {
+                    constraints.set(key, bound);
+                }
+            }
+            let input = match read_input(year, day, input) {
+                Ok(input) => input,
+                Err(err) => {
+                    eprintln!("Error reading input for day {day}: {err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            let params = match read_params(params, &param) {
+                Ok(params) => params,
+                Err(err) => {
+                    eprintln!("{err}");
+                    return ExitCode::FAILURE;
+                }
+            };
+            if !cipher(year, day, &input, &params, &constraints, json) {
+                return ExitCode::FAILURE;
+            }
+        }
+        Command::Gen { day, seed, size } => {
+            match gen::generate(single_year(year), day, seed, size.into()) {
+                Some(input) => print!("{input}"),
+                None => {
+                    eprintln!("No input generator for day {day}");
+                    return ExitCode::FAILURE;
+                }
+            }
+        }
+    }
+    ExitCode::SUCCESS
+}
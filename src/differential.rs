@@ -0,0 +1,258 @@
+//! Differential testing: every pair of registered variants for the same part must agree on
+//! every input, so run them on generated and mutated inputs and report the smallest input they
+//! disagree on.
+
+use std::{fmt, mem, ops::Range};
+
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::{
+    error::Result,
+    gen::{self, Size},
+    params::Params,
+    solution::{registry, Answer, DynSolution},
+};
+
+#[derive(Debug, Clone)]
+pub struct Options {
+    pub seeds: Range<u64>,
+    /// Mutated copies of each generated input to try as well.
+    pub mutations: usize,
+    pub size: Size,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            seeds: 0..100,
+            mutations: 10,
+            size: Size {
+                count: 30,
+                width: 30,
+                height: 10,
+                area: 200,
+            },
+        }
+    }
+}
+
+/// Two variants of the same part giving different results for `input`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Disagreement {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub variants: (Option<&'static str>, Option<&'static str>),
+    pub answers: (Result<Answer>, Result<Answer>),
+    pub input: String,
+}
+
+impl fmt::Display for Disagreement {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let variant = |name: Option<&str>| name.unwrap_or("default").to_string();
+        let answer = |answer: &Result<Answer>| match answer {
+            Ok(answer) => format!("{:?}", answer.to_string()),
+            Err(err) => format!("error `{err}`"),
+        };
+        writeln!(
+            f,
+            "{} day {} part {}: {} gives {} but {} gives {} for this input:",
+            self.year,
+            self.day,
+            self.part,
+            variant(self.variants.0),
+            answer(&self.answers.0),
+            variant(self.variants.1),
+            answer(&self.answers.1),
+        )?;
+        write!(f, "{}", self.input)
+    }
+}
+
+type Pair = (u8, Option<&'static str>, Option<&'static str>);
+
+/// Every pair of variants solving the same part, with each alternate generator paired with the
+/// default parts as well.
+fn pairs(solution: &dyn DynSolution) -> Vec<Pair> {
+    let variants = solution.variants();
+    let generators = solution.parsers().into_iter().flat_map(|name| {
+        variants
+            .iter()
+            .filter(|(_, variant)| variant.is_none())
+            .map(move |&(part, _)| (part, None, Some(name)))
+    });
+    variants
+        .iter()
+        .tuple_combinations()
+        .filter(|((a, _), (b, _))| a == b)
+        .map(|(&(part, a), &(_, b))| (part, a, b))
+        .chain(generators)
+        .collect()
+}
+
+/// Run both variants of `pair` on `input`, each with its own generator, returning their results
+/// if they differ. A generator error counts as that variant's result, and two errors agree if
+/// they are the same kind, whatever their details.
+fn compare(
+    solution: &dyn DynSolution,
+    (part, a, b): Pair,
+    input: &str,
+    params: &Params,
+) -> Option<(Result<Answer>, Result<Answer>)> {
+    let run = |name| {
+        solution
+            .parse(input, name, params)
+            .and_then(|parsed| solution.solve(&*parsed, part, name))
+    };
+    let answers = (run(a), run(b));
+    let agree = match &answers {
+        (Ok(a), Ok(b)) => a == b,
+        (Err(a), Err(b)) => mem::discriminant(a) == mem::discriminant(b),
+        _ => false,
+    };
+    (!agree).then_some(answers)
+}
+
+/// Look for a disagreement between any two variants of `day` of `year`, returning the first one
+/// found with its input minimized.
+pub fn check_day(year: u16, day: u8, options: &Options) -> Option<Disagreement> {
+    let registry = registry();
+    let solution = registry.get(&(year, day))?.as_ref();
+    let pairs = pairs(solution);
+    if pairs.is_empty() {
+        return None;
+    }
+
+    let params = gen::params(year, day, options.size);
+    for seed in options.seeds.clone() {
+        let generated = gen::generate(year, day, seed, options.size)?;
+        let mut rng = ChaCha8Rng::seed_from_u64(seed);
+        let inputs = std::iter::once(generated.clone())
+            .chain((0..options.mutations).map(|_| mutate(&mut rng, &generated)));
+        for input in inputs {
+            for &pair in &pairs {
+                if compare(solution, pair, &input, &params).is_some() {
+                    let input = minimize(&input, |input| {
+                        compare(solution, pair, input, &params).is_some()
+                    });
+                    let answers = compare(solution, pair, &input, &params)?;
+                    let (part, a, b) = pair;
+                    return Some(Disagreement {
+                        year,
+                        day,
+                        part,
+                        variants: (a, b),
+                        answers,
+                        input,
+                    });
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Apply one to three random edits: delete, duplicate or swap lines, or change a character to
+/// another of the same kind so it still looks like the format.
+fn mutate(rng: &mut impl Rng, input: &str) -> String {
+    let mut lines = input.lines().map(str::to_string).collect_vec();
+    for _ in 0..rng.gen_range(1..=3) {
+        if lines.is_empty() {
+            break;
+        }
+        let i = rng.gen_range(0..lines.len());
+        match rng.gen_range(0..4) {
+            0 => {
+                lines.remove(i);
+            }
+            1 => lines.insert(i, lines[i].clone()),
+            2 => {
+                let j = rng.gen_range(0..lines.len());
+                lines.swap(i, j);
+            }
+            _ => {
+                let mut chars = lines[i].chars().collect_vec();
+                if let Some(c) = chars.choose_mut(rng) {
+                    *c = match *c {
+                        '0'..='9' => rng.gen_range('0'..='9'),
+                        'a'..='z' => rng.gen_range('a'..='z'),
+                        'A'..='Z' => rng.gen_range('A'..='Z'),
+                        c => c,
+                    };
+                }
+                lines[i] = chars.into_iter().collect();
+            }
+        }
+    }
+    lines.into_iter().map(|line| line + "\n").collect()
+}
+
+/// Remove as many lines as possible while `fails` still holds, trying big chunks first.
+pub fn minimize(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let join = |lines: &[&str]| {
+        lines
+            .iter()
+            .map(|line| format!("{line}\n"))
+            .collect::<String>()
+    };
+    let mut lines = input.lines().collect_vec();
+    let mut chunk = (lines.len() / 2).max(1);
+    loop {
+        let mut removed = false;
+        let mut start = 0;
+        while start < lines.len() {
+            let end = (start + chunk).min(lines.len());
+            let candidate = [&lines[..start], &lines[end..]].concat();
+            if fails(&join(&candidate)) {
+                lines = candidate;
+                removed = true;
+            } else {
+                start = end;
+            }
+        }
+        if !removed {
+            if chunk == 1 {
+                break;
+            }
+            chunk /= 2;
+        }
+    }
+    join(&lines)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_minimize() {
+        let input = "1\n2\n3\n4\n5\n6\n7\n";
+        let fails = |input: &str| input.contains('3') && input.contains('6');
+        assert_eq!(minimize(input, fails), "3\n6\n");
+    }
+
+    #[test]
+    fn test_pairs() {
+        let registry = registry();
+        assert_eq!(
+            pairs(registry[&(2022, 3)].as_ref()),
+            vec![(1, None, Some("HashSet"))]
+        );
+        assert_eq!(pairs(registry[&(2022, 1)].as_ref()), vec![]);
+    }
+
+    #[test]
+    fn test_variants_agree() {
+        let options = Options {
+            seeds: 0..20,
+            ..Options::default()
+        };
+        for &(year, day) in registry().keys() {
+            if let Some(disagreement) = check_day(year, day, &options) {
+                panic!("{disagreement}");
+            }
+        }
+    }
+}
@@ -0,0 +1,50 @@
+//! Serde helpers for dumping parsed inputs as JSON. Hash maps and sets are written in sorted
+//! order so the same input always dumps the same way.
+
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet};
+
+use serde::{Serialize, Serializer};
+
+/// `#[serde(serialize_with = "dump::sorted_map")]` for a `HashMap` with sortable keys.
+pub fn sorted_map<K, V, S>(map: &HashMap<K, V>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    K: Ord + Serialize,
+    V: Serialize,
+    S: Serializer,
+{
+    map.iter().collect::<BTreeMap<_, _>>().serialize(serializer)
+}
+
+/// `#[serde(serialize_with = "dump::sorted_set")]` for a `HashSet` of sortable items.
+pub fn sorted_set<T, S>(set: &HashSet<T>, serializer: S) -> Result<S::Ok, S::Error>
+where
+    T: Ord + Serialize,
+    S: Serializer,
+{
+    set.iter().collect::<BTreeSet<_>>().serialize(serializer)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Example {
+        #[serde(serialize_with = "sorted_map")]
+        map: HashMap<String, u32>,
+        #[serde(serialize_with = "sorted_set")]
+        set: HashSet<u32>,
+    }
+
+    #[test]
+    fn test_sorted() {
+        let example = Example {
+            map: HashMap::from([("BB".to_string(), 2), ("AA".to_string(), 1)]),
+            set: HashSet::from([3, 1, 2]),
+        };
+        assert_eq!(
+            serde_json::to_string(&example).unwrap(),
+            r#"{"map":{"AA":1,"BB":2},"set":[1,2,3]}"#
+        );
+    }
+}
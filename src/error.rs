@@ -0,0 +1,139 @@
+use std::fmt;
+
+/// Everything that can go wrong while parsing or solving a puzzle.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    /// A line of input could not be parsed. `line` and `column` are 1-based.
+    Parse {
+        day: u8,
+        line: usize,
+        column: usize,
+        text: String,
+        reason: String,
+    },
+    /// The input parsed fine but has no answer, e.g. no start-of-packet marker.
+    NoSolution { day: u8, reason: String },
+    /// The part has not been solved yet.
+    Unimplemented { day: u8, part: u8 },
+    /// The input could not be read, e.g. from a reader that failed part way.
+    Io { day: u8, reason: String },
+    /// The puzzle parameters don't fit the day, e.g. an unknown or mistyped one.
+    InvalidParams { day: u8, reason: String },
+    /// No solver is registered for this day/part/name.
+    UnknownSolver {
+        day: u8,
+        part: u8,
+        name: Option<String>,
+    },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
+
+impl Error {
+    pub fn parse(
+        day: u8,
+        line: usize,
+        column: usize,
+        text: impl Into<String>,
+        reason: impl Into<String>,
+    ) -> Self {
+        Error::Parse {
+            day,
+            line,
+            column,
+            text: text.into(),
+            reason: reason.into(),
+        }
+    }
+
+    /// A parse error for `token`, which must be a slice of `line`.
+    pub fn parse_token(
+        day: u8,
+        line_no: usize,
+        line: &str,
+        token: &str,
+        reason: impl Into<String>,
+    ) -> Self {
+        Error::parse(day, line_no, column_of(line, token), token, reason)
+    }
+
+    pub fn no_solution(day: u8, reason: impl Into<String>) -> Self {
+        Error::NoSolution {
+            day,
+            reason: reason.into(),
+        }
+    }
+
+    pub fn invalid_params(day: u8, reason: impl Into<String>) -> Self {
+        Error::InvalidParams {
+            day,
+            reason: reason.into(),
+        }
+    }
+}
+
+/// 1-based column of `token` in `line`, or 1 if `token` isn't a slice of `line`.
+pub fn column_of(line: &str, token: &str) -> usize {
+    let start = line.as_ptr() as usize;
+    let offset = (token.as_ptr() as usize).wrapping_sub(start);
+    if offset <= line.len() {
+        line[..offset].chars().count() + 1
+    } else {
+        1
+    }
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::Parse {
+                day,
+                line,
+                column,
+                text,
+                reason,
+            } => write!(
+                f,
+                "day {day}, line {line}, column {column}: {reason}: {text:?}"
+            ),
+            Error::NoSolution { day, reason } => write!(f, "day {day}: no solution: {reason}"),
+            Error::Io { day, reason } => write!(f, "day {day}: error reading input: {reason}"),
+            Error::InvalidParams { day, reason } => {
+                write!(f, "day {day}: invalid parameters: {reason}")
+            }
+            Error::Unimplemented { day, part } => {
+                write!(f, "day {day} part {part} is not implemented")
+            }
+            Error::UnknownSolver { day, part, name } => match name {
+                Some(name) => write!(f, "no solver registered for day {day} part {part} {name}"),
+                None => write!(f, "no solver registered for day {day} part {part}"),
+            },
+        }
+    }
+}
+
+impl std::error::Error for Error {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_column_of() {
+        let line = "move 1 from 2 to 3";
+        let token = line.split_whitespace().nth(2).unwrap();
+        assert_eq!(column_of(line, token), 8);
+        let owned = String::from(token);
+        assert_eq!(column_of(line, &owned), 1);
+    }
+
+    #[test]
+    fn test_display() {
+        let line = "A Q";
+        let err = Error::parse_token(2, 3, line, &line[2..], "invalid move");
+        assert_eq!(
+            err.to_string(),
+            "day 2, line 3, column 3: invalid move: \"Q\""
+        );
+    }
+}
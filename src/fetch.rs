@@ -0,0 +1,298 @@
+//! Puzzle inputs on disk, downloaded when missing. Inputs live in `input/YYYY/dayNN.txt`, next to
+//! a `dayNN.sha256` written when the input was downloaded, so an input that was edited or cut
+//! short is caught instead of giving wrong answers.
+//!
+//! Downloads use the session cookie in `ADVENT_SESSION` and go to `ADVENT_BASE_URL`, which
+//! defaults to the Advent of Code site and can point at any server with the same layout.
+
+use std::{
+    env, fmt, fs, io,
+    path::{Path, PathBuf},
+};
+
+use tracing::info;
+
+use crate::answers::input_hash;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+pub const SESSION_VAR: &str = "ADVENT_SESSION";
+pub const BASE_URL_VAR: &str = "ADVENT_BASE_URL";
+
+#[derive(Debug)]
+pub enum FetchError {
+    Io(io::Error),
+    /// The input isn't cached and there's no session token to download it with.
+    NoSession {
+        day: u8,
+    },
+    Http {
+        day: u8,
+        reason: String,
+    },
+    /// The cached input doesn't match the hash recorded when it was downloaded.
+    HashMismatch {
+        day: u8,
+        expected: String,
+        actual: String,
+    },
+}
+
+impl fmt::Display for FetchError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            FetchError::Io(err) => write!(f, "{err}"),
+            FetchError::NoSession { day } => write!(
+                f,
+                "no input for day {day}, and {SESSION_VAR} is not set to download it"
+            ),
+            FetchError::Http { day, reason } => {
+                write!(f, "downloading the input for day {day} failed: {reason}")
+            }
+            FetchError::HashMismatch {
+                day,
+                expected,
+                actual,
+            } => write!(
+                f,
+                "the input for day {day} has hash {actual}, but {expected} was downloaded"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for FetchError {}
+
+impl From<io::Error> for FetchError {
+    fn from(err: io::Error) -> Self {
+        FetchError::Io(err)
+    }
+}
+
+/// Where inputs are cached and where missing ones are downloaded from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Fetcher {
+    pub year: u16,
+    pub dir: PathBuf,
+    pub base_url: String,
+    pub session: Option<String>,
+}
+
+impl Fetcher {
+    /// Inputs for `year` in `input/YYYY`, downloaded from the puzzle site without a session.
+    pub fn new(year: u16) -> Self {
+        Fetcher {
+            year,
+            dir: PathBuf::from(format!("input/{year}")),
+            base_url: DEFAULT_BASE_URL.to_string(),
+            session: None,
+        }
+    }
+
+    /// The default cache directory for `year`, with the base URL and session token from the
+    /// environment.
+    pub fn from_env(year: u16) -> Self {
+        Fetcher {
+            base_url: env::var(BASE_URL_VAR).unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+            session: env::var(SESSION_VAR).ok().filter(|s| !s.trim().is_empty()),
+            ..Fetcher::new(year)
+        }
+    }
+
+    pub fn path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{day:02}.txt"))
+    }
+
+    fn hash_path(&self, day: u8) -> PathBuf {
+        self.dir.join(format!("day{day:02}.sha256"))
+    }
+
+    pub fn url(&self, day: u8) -> String {
+        format!(
+            "{}/{}/day/{day}/input",
+            self.base_url.trim_end_matches('/'),
+            self.year
+        )
+    }
+
+    /// The cached input for `day`, checked against its recorded hash, or `None` if there isn't
+    /// one. cargo-aoc's `input/YYYY/dayN.txt` is used if `dayNN.txt` doesn't exist.
+    pub fn cached(&self, day: u8) -> Result<Option<String>, FetchError> {
+        let input = match read_if_exists(&self.path(day))? {
+            Some(input) => input,
+            None => match read_if_exists(&self.dir.join(format!("day{day}.txt")))? {
+                Some(input) => input,
+                None => return Ok(None),
+            },
+        };
+        if let Some(expected) = read_if_exists(&self.hash_path(day))? {
+            let expected = expected.trim().to_string();
+            let actual = input_hash(&input);
+            if expected != actual {
+                return Err(FetchError::HashMismatch {
+                    day,
+                    expected,
+                    actual,
+                });
+            }
+        }
+        Ok(Some(input))
+    }
+
+    /// The input for `day`, downloading and caching it if it isn't cached yet.
+    pub fn get(&self, day: u8) -> Result<String, FetchError> {
+        if let Some(input) = self.cached(day)? {
+            return Ok(input);
+        }
+        let input = self.download(day)?;
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(day), &input)?;
+        fs::write(self.hash_path(day), input_hash(&input) + "\n")?;
+        Ok(input)
+    }
+
+    fn download(&self, day: u8) -> Result<String, FetchError> {
+        let session = self
+            .session
+            .as_deref()
+            .ok_or(FetchError::NoSession { day })?;
+        let url = self.url(day);
+        info!(url, "downloading input");
+        let http = |reason: String| FetchError::Http { day, reason };
+        let response = ureq::get(&url)
+            .set("Cookie", &format!("session={}", session.trim()))
+            .set("User-Agent", "advent_2022 input fetcher")
+            .call()
+            .map_err(|err| match err {
+                ureq::Error::Status(status, response) => http(format!(
+                    "{url} returned {status} {}",
+                    response.status_text()
+                )),
+                err => http(err.to_string()),
+            })?;
+        let input = response.into_string()?;
+        if input.trim().is_empty() {
+            return Err(http(format!("{url} returned an empty input")));
+        }
+        Ok(input)
+    }
+}
+
+fn read_if_exists(path: &Path) -> io::Result<Option<String>> {
+    match fs::read_to_string(path) {
+        Ok(contents) => Ok(Some(contents)),
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(None),
+        Err(err) => Err(err),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        thread::{self, JoinHandle},
+    };
+
+    use super::*;
+
+    /// A stand-in for the puzzle site that answers `responses` in order, one per connection, and
+    /// returns the request lines it received.
+    fn serve(responses: Vec<(u16, &'static str)>) -> (String, JoinHandle<Vec<String>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let server = thread::spawn(move || {
+            let mut requests = vec![];
+            for (status, body) in responses {
+                let (mut stream, _) = listener.accept().unwrap();
+                let mut reader = BufReader::new(stream.try_clone().unwrap());
+                let mut request = vec![];
+                loop {
+                    let mut line = String::new();
+                    reader.read_line(&mut line).unwrap();
+                    if line.trim().is_empty() {
+                        break;
+                    }
+                    request.push(line.trim().to_string());
+                }
+                requests.push(request.join("\n"));
+                write!(
+                    stream,
+                    "HTTP/1.1 {status} Status\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{body}",
+                    body.len()
+                )
+                .unwrap();
+            }
+            requests
+        });
+        (base_url, server)
+    }
+
+    fn fetcher(base_url: String, name: &str) -> Fetcher {
+        let dir = env::temp_dir().join(format!("advent-fetch-{name}-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        Fetcher {
+            year: 2022,
+            dir,
+            base_url,
+            session: Some("secret".to_string()),
+        }
+    }
+
+    #[test]
+    fn test_download_and_cache() {
+        let (base_url, server) = serve(vec![(200, "A Y\nB X\nC Z\n")]);
+        let fetcher = fetcher(base_url, "cache");
+
+        assert_eq!(fetcher.cached(2).unwrap(), None);
+        assert_eq!(fetcher.get(2).unwrap(), "A Y\nB X\nC Z\n");
+        // served from the cache, the stand-in only answers once
+        assert_eq!(fetcher.get(2).unwrap(), "A Y\nB X\nC Z\n");
+        assert!(fetcher.path(2).ends_with("day02.txt"));
+
+        let requests = server.join().unwrap();
+        assert_eq!(requests.len(), 1);
+        assert!(requests[0].starts_with("GET /2022/day/2/input HTTP/1.1"));
+        assert!(requests[0].contains("Cookie: session=secret"));
+
+        fs::write(fetcher.path(2), "A Y\n").unwrap();
+        assert!(matches!(
+            fetcher.get(2),
+            Err(FetchError::HashMismatch { day: 2, .. })
+        ));
+        fs::remove_dir_all(&fetcher.dir).unwrap();
+    }
+
+    #[test]
+    fn test_download_errors() {
+        let (base_url, server) = serve(vec![(404, "Not found"), (200, "\n")]);
+        let fetcher = fetcher(base_url, "errors");
+        let err = fetcher.get(3).unwrap_err();
+        assert!(err.to_string().contains("returned 404"), "{err}");
+        let err = fetcher.get(3).unwrap_err();
+        assert!(err.to_string().contains("empty input"), "{err}");
+        server.join().unwrap();
+        assert!(!fetcher.path(3).exists());
+
+        let offline = Fetcher {
+            session: None,
+            ..fetcher
+        };
+        assert!(matches!(
+            offline.get(3),
+            Err(FetchError::NoSession { day: 3 })
+        ));
+    }
+
+    #[test]
+    fn test_cargo_aoc_layout() {
+        let fetcher = fetcher("http://127.0.0.1:9".to_string(), "layout");
+        fs::create_dir_all(&fetcher.dir).unwrap();
+        fs::write(
+            fetcher.dir.join("day6.txt"),
+            "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n",
+        )
+        .unwrap();
+        assert_eq!(fetcher.get(6).unwrap(), "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n");
+        fs::remove_dir_all(&fetcher.dir).unwrap();
+    }
+}
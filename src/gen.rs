@@ -0,0 +1,45 @@
+//! Seeded random puzzle inputs, for stress testing and benchmarking the solvers on more than the
+//! sample inputs. The same year, day, seed and size always give the same input.
+
+use crate::{params::Params, y2022};
+
+/// How big a generated input should be.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Size {
+    /// Number of records: elves, rounds, rucksack groups, moves, characters, directories,
+    /// motions, instructions, monkeys, packet pairs, rock paths, sensors or valves.
+    pub count: usize,
+    /// Grid width for days 8 and 12. Day 12 needs at least 26 columns to climb from `a` to `z`.
+    pub width: usize,
+    pub height: usize,
+    /// Side of the square day 15 searches for the distress beacon.
+    pub area: usize,
+}
+
+impl Default for Size {
+    fn default() -> Self {
+        Size {
+            count: 100,
+            width: 40,
+            height: 30,
+            area: 4_000_000,
+        }
+    }
+}
+
+/// A random but valid input for `day` of `year`, or `None` if there is no generator for it.
+pub fn generate(year: u16, day: u8, seed: u64, size: Size) -> Option<String> {
+    match year {
+        y2022::YEAR => y2022::gen::generate(day, seed, size),
+        _ => None,
+    }
+}
+
+/// The parameters an input generated for `day` of `year` at `size` has to be solved with, where
+/// the size changes the puzzle's constants.
+pub fn params(year: u16, day: u8, size: Size) -> Params {
+    match year {
+        y2022::YEAR => y2022::gen::params(day, size),
+        _ => Params::default(),
+    }
+}
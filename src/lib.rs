@@ -1,34 +1,42 @@
-pub mod day01;
-pub mod day02;
-pub mod day03;
-pub mod day04;
-pub mod day05;
-pub mod day06;
-pub mod day07;
-pub mod day08;
-pub mod day09;
-pub mod day10;
-pub mod day11;
-pub mod day12;
-pub mod day13;
-pub mod day14;
-pub mod day15;
-pub mod day16;
+//! Advent of Code solutions, one module per year, and the tools to run, check and measure them.
+//!
+//! Besides [`solution::registry`], which runs any day by number, the puzzles' building blocks
+//! are public so other crates and integration tests can reuse them:
+//!
+//! - parsers: every solved day's [`solution::Solution::parse`] returns its typed input, and most
+//!   days export the parser too, e.g. [`y2022::day11::parse_monkeys`],
+//!   [`y2022::day13::parse_pairs`] or [`y2022::day15::parse_sensors`];
+//! - parameterized solvers: [`y2022::day01::top_elves`] for any number of elves, streaming
+//!   from a reader, [`y2022::day09::tail_positions`] for any number of knots,
+//!   [`y2022::day11::monkey_business`] for any number of rounds,
+//!   [`y2022::day15::positions_without_beacon`] for any row and
+//!   [`y2022::day16::release_pressure`] for any start and time limit;
+//! - reusable pieces: rope physics in [`y2022::day09::Rope`], packet ordering in
+//!   [`y2022::day13::compare`], interval merging in [`util::intervals`], valve graph compression
+//!   in [`y2022::day16::compress`] and table-driven games in [`y2022::day02::Game`], along with
+//!   [`util::grid`] and [`util::parse`];
+//! - analysis: [`y2022::day01::Inventory`] ranks the elves and summarizes their calories.
+
+pub mod alloc;
+pub mod answers;
+pub mod differential;
+pub mod dump;
+pub mod error;
+pub mod fetch;
+pub mod gen;
+pub mod params;
+pub mod report;
+pub mod runner;
+pub mod scaffold;
+pub mod solution;
+pub mod util;
+pub mod viz;
+pub mod y2022;
 
 extern crate aoc_runner;
 
 #[macro_use]
 extern crate aoc_runner_derive;
 
+// cargo-aoc only knows one year per crate, so it runs 2022 and other years use `advent`
 aoc_lib! { year = 2022 }
-
-#[cfg(windows)]
-pub const LINE_ENDING: &str = "\r\n";
-#[cfg(not(windows))]
-pub const LINE_ENDING: &str = "\n";
-
-pub fn double_line_ending() -> String {
-    let mut token = LINE_ENDING.to_owned();
-    token.push_str(LINE_ENDING);
-    token
-}
@@ -0,0 +1,118 @@
+//! Puzzle parameters: the constants a day's puzzle text fixes, like the row day 15 asks about,
+//! so the sample-sized puzzles and variants run through the same entry point as the real ones.
+
+use std::{fs, io, path::Path};
+
+use serde::de::DeserializeOwned;
+use serde_json::{Map, Value};
+
+use crate::error::{Error, Result};
+
+/// Parameters for one day, as given with `--param key=value` or in a JSON file. Each day reads
+/// them into its own `Params` type, with the puzzle's values for anything not set.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Params(Map<String, Value>);
+
+impl Params {
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    pub fn set(&mut self, key: impl Into<String>, value: impl Into<Value>) {
+        self.0.insert(key.into(), value.into());
+    }
+
+    /// Set a parameter from `key=value`. The value is read as JSON, falling back to a plain
+    /// string, so `row=10` gives a number and `start=AA` a string.
+    pub fn set_flag(&mut self, flag: &str) -> std::result::Result<(), String> {
+        let (key, value) = flag
+            .split_once('=')
+            .ok_or_else(|| format!("invalid parameter `{flag}`, expected `key=value`"))?;
+        let value = serde_json::from_str(value).unwrap_or_else(|_| Value::from(value));
+        self.set(key.trim(), value);
+        Ok(())
+    }
+
+    /// Read parameters from a JSON object like `{"row": 10, "bound": 20}`.
+    pub fn load(path: &Path) -> io::Result<Params> {
+        let json = fs::read_to_string(path)?;
+        serde_json::from_str(&json)
+            .map(Params)
+            .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    /// Add `other` on top, replacing parameters set in both.
+    pub fn merge(&mut self, other: Params) {
+        self.0.extend(other.0);
+    }
+
+    /// The parameters for `day` as `T`, which should be `#[serde(default, deny_unknown_fields)]`
+    /// so unset parameters keep the puzzle's values and misspelt ones are reported.
+    pub fn get<T: DeserializeOwned>(&self, day: u8) -> Result<T> {
+        serde_json::from_value(Value::Object(self.0.clone()))
+            .map_err(|err| Error::invalid_params(day, err.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde::Deserialize;
+
+    use super::*;
+
+    #[derive(Debug, PartialEq, Deserialize)]
+    #[serde(default, deny_unknown_fields)]
+    struct Example {
+        row: isize,
+        start: String,
+    }
+
+    impl Default for Example {
+        fn default() -> Self {
+            Example {
+                row: 2000000,
+                start: "AA".to_string(),
+            }
+        }
+    }
+
+    #[test]
+    fn test_get() {
+        let mut params = Params::default();
+        assert_eq!(params.get(15), Ok(Example::default()));
+
+        params.set_flag("row=10").unwrap();
+        params.set_flag("start=BB").unwrap();
+        assert_eq!(
+            params.get(15),
+            Ok(Example {
+                row: 10,
+                start: "BB".to_string()
+            })
+        );
+
+        params.set_flag("rows=10").unwrap();
+        assert!(matches!(
+            params.get::<Example>(15),
+            Err(Error::InvalidParams { day: 15, .. })
+        ));
+        assert!(params.set_flag("row").is_err());
+    }
+
+    #[test]
+    fn test_merge() {
+        let mut params = Params::default();
+        params.set("row", 10);
+        params.set("start", "BB");
+        let mut flags = Params::default();
+        flags.set("row", 20);
+        params.merge(flags);
+        assert_eq!(
+            params.get(15),
+            Ok(Example {
+                row: 20,
+                start: "BB".to_string()
+            })
+        );
+    }
+}
@@ -0,0 +1,375 @@
+//! Machine-readable run reports, for CI and batch runs: one entry per solver run with its answer,
+//! timings, memory and input hash, saved as JSON so reports from two commits can be compared.
+
+use std::{
+    collections::BTreeMap,
+    fmt, fs, io,
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    answers::input_hash,
+    runner::{self, Solver},
+};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Report {
+    pub entries: Vec<Entry>,
+}
+
+/// One solver run. Times are in nanoseconds.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    /// Name of the alternate solver, `None` for the default one.
+    pub variant: Option<String>,
+    pub input_sha256: String,
+    pub answer: Option<String>,
+    pub error: Option<String>,
+    pub parse_ns: u64,
+    pub solve_ns: u64,
+    /// Most bytes allocated at once while parsing or solving, when it was measured.
+    pub peak_alloc: Option<u64>,
+}
+
+impl Entry {
+    pub fn key(&self) -> Key {
+        Key {
+            year: self.year,
+            day: self.day,
+            part: self.part,
+            variant: self.variant.clone(),
+        }
+    }
+
+    pub fn total(&self) -> Duration {
+        Duration::from_nanos(self.parse_ns + self.solve_ns)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Key {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub variant: Option<String>,
+}
+
+impl fmt::Display for Key {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} day{} part{}", self.year, self.day, self.part)?;
+        if let Some(variant) = &self.variant {
+            write!(f, " {variant}")?;
+        }
+        Ok(())
+    }
+}
+
+fn nanos(duration: Duration) -> u64 {
+    duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+impl Report {
+    /// Run the solvers for every year and day in `inputs` one at a time, so timings don't compete
+    /// for cores. Alternates are included when `variants` is set.
+    pub fn generate(inputs: &BTreeMap<(u16, u8), String>, variants: bool) -> Report {
+        let entries = runner::solvers()
+            .into_iter()
+            .filter(|solver| variants || solver.name.is_none())
+            .filter_map(|solver| {
+                Some(Report::entry(
+                    solver,
+                    inputs.get(&(solver.year, solver.day))?,
+                ))
+            })
+            .collect();
+        Report { entries }
+    }
+
+    fn entry(solver: Solver, input: &str) -> Entry {
+        let start = Instant::now();
+        let result = solver.run(input);
+        let mut entry = Entry {
+            year: solver.year,
+            day: solver.day,
+            part: solver.part,
+            variant: solver.name.map(str::to_string),
+            input_sha256: input_hash(input),
+            answer: None,
+            error: None,
+            parse_ns: 0,
+            solve_ns: 0,
+            peak_alloc: None,
+        };
+        match result {
+            Ok(run) => {
+                entry.answer = Some(run.answer.to_string());
+                entry.parse_ns = nanos(run.parse_time);
+                entry.solve_ns = nanos(run.solve_time);
+                entry.peak_alloc = run.peak_alloc().map(|bytes| bytes as u64);
+            }
+            Err(err) => {
+                entry.error = Some(err.to_string());
+                entry.solve_ns = nanos(start.elapsed());
+            }
+        }
+        entry
+    }
+
+    pub fn load(path: &Path) -> io::Result<Report> {
+        let json = fs::read_to_string(path)?;
+        serde_json::from_str(&json).map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))
+    }
+
+    pub fn to_json(&self) -> String {
+        serde_json::to_string_pretty(self).expect("reports serialize to JSON")
+    }
+}
+
+/// How an entry differs between two reports.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Change {
+    Added(Key),
+    Removed(Key),
+    /// The answer or error changed. Not a regression if the input did as well.
+    Answer {
+        key: Key,
+        old: String,
+        new: String,
+        input_changed: bool,
+    },
+    Time {
+        key: Key,
+        old: Duration,
+        new: Duration,
+    },
+    PeakAlloc {
+        key: Key,
+        old: u64,
+        new: u64,
+    },
+}
+
+impl Change {
+    /// Whether the change should fail a CI run: a different answer for the same input, a solver
+    /// that disappeared, or one that got slower or hungrier.
+    pub fn is_regression(&self) -> bool {
+        match self {
+            Change::Added(_) => false,
+            Change::Removed(_) => true,
+            Change::Answer { input_changed, .. } => !input_changed,
+            Change::Time { old, new, .. } => new > old,
+            Change::PeakAlloc { old, new, .. } => new > old,
+        }
+    }
+}
+
+/// The change from `old` to `new` in percent, or `from 0` when there is nothing to be relative to.
+fn percent(old: f64, new: f64) -> String {
+    if old == 0.0 {
+        return "from 0".to_string();
+    }
+    format!("{:+.1}%", (new - old) / old * 100.0)
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Change::Added(key) => write!(f, "{key}: new"),
+            Change::Removed(key) => write!(f, "{key}: missing"),
+            Change::Answer {
+                key,
+                old,
+                new,
+                input_changed,
+            } => {
+                write!(f, "{key}: answer {old} -> {new}")?;
+                if *input_changed {
+                    write!(f, " (input changed)")?;
+                }
+                Ok(())
+            }
+            Change::Time { key, old, new } => write!(
+                f,
+                "{key}: {old:?} -> {new:?} ({})",
+                percent(old.as_secs_f64(), new.as_secs_f64())
+            ),
+            Change::PeakAlloc { key, old, new } => write!(
+                f,
+                "{key}: peak allocation {old} -> {new} bytes ({})",
+                percent(*old as f64, *new as f64)
+            ),
+        }
+    }
+}
+
+/// When two reports count as different.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Thresholds {
+    /// Relative change in total time or peak allocation, e.g. `0.2` for 20%.
+    pub ratio: f64,
+    /// Time changes smaller than this are noise.
+    pub min_time: Duration,
+}
+
+impl Default for Thresholds {
+    fn default() -> Self {
+        Thresholds {
+            ratio: 0.2,
+            min_time: Duration::from_millis(1),
+        }
+    }
+}
+
+fn outcome(entry: &Entry) -> String {
+    match (&entry.answer, &entry.error) {
+        (Some(answer), _) => answer.clone(),
+        (None, Some(error)) => format!("error: {error}"),
+        (None, None) => "nothing".to_string(),
+    }
+}
+
+/// Differences from `old` to `new`, in year, day, part and variant order, followed by the entries only
+/// `new` has.
+pub fn compare(old: &Report, new: &Report, thresholds: Thresholds) -> Vec<Change> {
+    let old: BTreeMap<Key, &Entry> = old.entries.iter().map(|e| (e.key(), e)).collect();
+    let new: BTreeMap<Key, &Entry> = new.entries.iter().map(|e| (e.key(), e)).collect();
+    // nothing is a ratio of 0, so any difference from it counts, down to `min_time` for times
+    let beyond = |old: f64, new: f64| {
+        if old == 0.0 {
+            new != old
+        } else {
+            (new - old).abs() > old * thresholds.ratio
+        }
+    };
+
+    let mut changes = vec![];
+    for (key, old_entry) in &old {
+        let Some(new_entry) = new.get(key) else {
+            changes.push(Change::Removed(key.clone()));
+            continue;
+        };
+        let (old_outcome, new_outcome) = (outcome(old_entry), outcome(new_entry));
+        if old_outcome != new_outcome {
+            changes.push(Change::Answer {
+                key: key.clone(),
+                old: old_outcome,
+                new: new_outcome,
+                input_changed: old_entry.input_sha256 != new_entry.input_sha256,
+            });
+        }
+
+        let (old_time, new_time) = (old_entry.total(), new_entry.total());
+        if old_time.abs_diff(new_time) >= thresholds.min_time
+            && beyond(old_time.as_secs_f64(), new_time.as_secs_f64())
+        {
+            changes.push(Change::Time {
+                key: key.clone(),
+                old: old_time,
+                new: new_time,
+            });
+        }
+
+        if let (Some(old_peak), Some(new_peak)) = (old_entry.peak_alloc, new_entry.peak_alloc) {
+            if beyond(old_peak as f64, new_peak as f64) {
+                changes.push(Change::PeakAlloc {
+                    key: key.clone(),
+                    old: old_peak,
+                    new: new_peak,
+                });
+            }
+        }
+    }
+    changes.extend(
+        new.keys()
+            .filter(|key| !old.contains_key(*key))
+            .map(|key| Change::Added(key.clone())),
+    );
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn report() -> Report {
+        let inputs = BTreeMap::from([((2022, 2), "A Y\nB X\nC Z\n".to_string())]);
+        Report::generate(&inputs, true)
+    }
+
+    #[test]
+    fn test_generate() {
+        let report = report();
+        assert_eq!(report.entries.len(), 2);
+        assert_eq!(
+            report.entries[0].key(),
+            Key {
+                year: 2022,
+                day: 2,
+                part: 1,
+                variant: None
+            }
+        );
+        assert_eq!(report.entries[0].answer.as_deref(), Some("15"));
+        assert_eq!(report.entries[1].answer.as_deref(), Some("12"));
+        assert_eq!(
+            report.entries[0].input_sha256,
+            input_hash("A Y\nB X\nC Z\n")
+        );
+
+        let json = report.to_json();
+        assert_eq!(serde_json::from_str::<Report>(&json).unwrap(), report);
+        let without_year = json.replace("\"year\": 2022,", "");
+        assert!(serde_json::from_str::<Report>(&without_year).is_err());
+    }
+
+    #[test]
+    fn test_compare() {
+        let mut old = report();
+        for entry in &mut old.entries {
+            entry.parse_ns = 0;
+            entry.solve_ns = 10_000_000;
+            entry.peak_alloc = Some(1000);
+        }
+        assert_eq!(compare(&old, &old, Thresholds::default()), vec![]);
+
+        let mut new = old.clone();
+        new.entries[0].solve_ns = 11_000_000;
+        new.entries[1].solve_ns = 20_000_000;
+        new.entries[1].peak_alloc = Some(500);
+        let changes = compare(&old, &new, Thresholds::default());
+        assert_eq!(changes.len(), 2);
+        assert!(changes[0].is_regression());
+        assert_eq!(
+            changes[0].to_string(),
+            "2022 day2 part2: 10ms -> 20ms (+100.0%)"
+        );
+        assert!(!changes[1].is_regression());
+
+        let mut from_zero = old.clone();
+        from_zero.entries[1].peak_alloc = Some(0);
+        let changes = compare(&from_zero, &old, Thresholds::default());
+        assert_eq!(
+            changes[0].to_string(),
+            "2022 day2 part2: peak allocation 0 -> 1000 bytes (from 0)"
+        );
+        assert_eq!(
+            compare(&from_zero, &from_zero, Thresholds::default()),
+            vec![]
+        );
+
+        new.entries[0].answer = Some("16".to_string());
+        new.entries[1].variant = Some("fast".to_string());
+        let changes = compare(&old, &new, Thresholds::default());
+        assert_eq!(changes[0].to_string(), "2022 day2 part1: answer 15 -> 16");
+        assert!(changes[0].is_regression());
+        assert_eq!(changes[1], Change::Removed(old.entries[1].key()));
+        assert_eq!(changes[2], Change::Added(new.entries[1].key()));
+        assert!(!changes[2].is_regression());
+    }
+}
@@ -0,0 +1,402 @@
+use std::{
+    collections::BTreeMap,
+    time::{Duration, Instant},
+};
+
+use itertools::Itertools;
+use rayon::prelude::*;
+use serde_json::Value;
+use tracing::info_span;
+
+use crate::{
+    alloc::{self, Usage},
+    error::Result,
+    params::Params,
+    solution::{registry, Answer},
+    viz::Frame,
+    y2022::{self, day02},
+};
+
+/// A single runnable solution, mirroring what `#[aoc(dayN, partN, name)]` registers.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Solver {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub name: Option<&'static str>,
+}
+
+#[derive(Debug, Clone)]
+pub struct Run {
+    pub answer: Answer,
+    pub parse_time: Duration,
+    pub solve_time: Duration,
+    /// What the generator and the part allocated, when built with the `count-alloc` feature.
+    pub parse_alloc: Option<Usage>,
+    pub solve_alloc: Option<Usage>,
+}
+
+impl Run {
+    /// The higher of the generator's and the part's peak allocation.
+    pub fn peak_alloc(&self) -> Option<usize> {
+        Some(self.parse_alloc?.peak.max(self.solve_alloc?.peak))
+    }
+}
+
+impl Solver {
+    pub fn run(&self, input: &str) -> Result<Run> {
+        self.run_with(input, &Params::default())
+    }
+
+    pub fn run_with(&self, input: &str, params: &Params) -> Result<Run> {
+        self.execute(input, params, true)
+    }
+
+    /// Run without measuring allocations, for when other solvers run at the same time and would
+    /// show up in the process-wide counters.
+    fn execute(&self, input: &str, params: &Params, measure: bool) -> Result<Run> {
+        let registry = registry();
+        let solution = &registry[&(self.year, self.day)];
+        let _day = info_span!("day", year = self.year, day = self.day).entered();
+
+        let start = Instant::now();
+        let (parsed, parse_alloc) = info_span!("generator", name = self.name)
+            .in_scope(|| measure_if(measure, || solution.parse(input, self.name, params)));
+        let parsed = parsed?;
+        let parse_time = start.elapsed();
+
+        let start = Instant::now();
+        let (answer, solve_alloc) = info_span!("part", part = self.part, name = self.name)
+            .in_scope(|| measure_if(measure, || solution.solve(&*parsed, self.part, self.name)));
+        let answer = answer?;
+        let solve_time = start.elapsed();
+
+        Ok(Run {
+            answer,
+            parse_time,
+            solve_time,
+            parse_alloc,
+            solve_alloc,
+        })
+    }
+}
+
+fn measure_if<T>(measure: bool, f: impl FnOnce() -> T) -> (T, Option<Usage>) {
+    if measure {
+        alloc::measure(f)
+    } else {
+        (f(), None)
+    }
+}
+
+/// Every solver of every year, in year, day, part and name order.
+pub fn solvers() -> Vec<Solver> {
+    registry()
+        .iter()
+        .flat_map(|(&(year, day), solution)| {
+            solution
+                .variants()
+                .into_iter()
+                .map(move |(part, name)| Solver {
+                    year,
+                    day,
+                    part,
+                    name,
+                })
+        })
+        .collect_vec()
+}
+
+pub fn years() -> Vec<u16> {
+    registry().keys().map(|&(year, _)| year).dedup().collect()
+}
+
+pub fn days(year: u16) -> Vec<u8> {
+    registry()
+        .keys()
+        .filter(|&&(y, _)| y == year)
+        .map(|&(_, day)| day)
+        .collect()
+}
+
+/// Every alternate generator of every year, as year, day and name.
+pub fn generators() -> Vec<(u16, u8, &'static str)> {
+    registry()
+        .iter()
+        .flat_map(|(&(year, day), solution)| {
+            solution
+                .parsers()
+                .into_iter()
+                .map(move |name| (year, day, name))
+        })
+        .collect()
+}
+
+/// Find a solver, matching alternate names case-insensitively. `None` selects the default. The
+/// name of an alternate generator selects the default part, parsed with that generator.
+pub fn find(year: u16, day: u8, part: u8, name: Option<&str>) -> Option<Solver> {
+    let matches = |a: &str| name.is_some_and(|b| a.eq_ignore_ascii_case(b));
+    solvers()
+        .into_iter()
+        .find(|solver| {
+            solver.year == year
+                && solver.day == day
+                && solver.part == part
+                && match solver.name {
+                    None => name.is_none(),
+                    Some(a) => matches(a),
+                }
+        })
+        .or_else(|| {
+            let (_, _, generator) = generators()
+                .into_iter()
+                .find(|&(y, d, generator)| (y, d) == (year, day) && matches(generator))?;
+            find(year, day, part, None).map(|solver| Solver {
+                name: Some(generator),
+                ..solver
+            })
+        })
+}
+
+/// Parse `input` for `day` and draw it, or `None` if the day has no visualization.
+pub fn render(year: u16, day: u8, input: &str, params: &Params) -> Result<Option<Vec<Frame>>> {
+    let registry = registry();
+    let solution = &registry[&(year, day)];
+    let _day = info_span!("day", year, day).entered();
+    let parsed = solution.parse(input, None, params)?;
+    info_span!("render")
+        .in_scope(|| solution.render(&*parsed))
+        .transpose()
+}
+
+/// Parse `input` for `day`, with the generator of the solver called `name` if it has its own,
+/// and return what the generator made of it as JSON.
+pub fn dump(year: u16, day: u8, input: &str, name: Option<&str>, params: &Params) -> Result<Value> {
+    let registry = registry();
+    let solution = &registry[&(year, day)];
+    let parsed = solution.parse(input, name, params)?;
+    Ok(solution.dump(&*parsed))
+}
+
+/// Every reading of `input` for `day` that meets `constraints`, or `None` if the day's input
+/// can only be read one way. Only day 2 of 2022 can be read more than one way.
+pub fn decipher(
+    year: u16,
+    day: u8,
+    input: &str,
+    params: &Params,
+    constraints: &Params,
+) -> Result<Option<Vec<day02::Interpretation>>> {
+    if (year, day) != (y2022::YEAR, 2) {
+        return Ok(None);
+    }
+    let _day = info_span!("day", year, day).entered();
+    info_span!("decipher")
+        .in_scope(|| day02::decipher(input, params, constraints))
+        .map(Some)
+}
+
+/// Run both default parts of every day in `inputs`, keyed by year and day, concurrently. Results
+/// come back in year, day and part order. Allocations aren't measured: with every solver running
+/// at once, the counters couldn't tell them apart.
+pub fn run_all(inputs: &BTreeMap<(u16, u8), String>) -> Vec<(Solver, Result<Run>)> {
+    solvers()
+        .into_par_iter()
+        .filter(|solver| solver.name.is_none())
+        .filter_map(|solver| {
+            let input = inputs.get(&(solver.year, solver.day))?;
+            Some((solver, solver.execute(input, &Params::default(), false)))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{
+        fs, io,
+        sync::{Arc, Mutex},
+    };
+
+    use super::*;
+    use crate::error::Error;
+
+    #[test]
+    fn test_find() {
+        assert!(find(2022, 3, 1, None).is_some());
+        assert_eq!(
+            find(2022, 3, 1, Some("hashset")).unwrap().name,
+            Some("HashSet")
+        );
+        assert_eq!(
+            find(2022, 10, 2, Some("chunks")).unwrap().name,
+            Some("chunks")
+        );
+        assert!(find(2022, 3, 2, Some("HashSet")).is_none());
+        assert!(find(2022, 42, 1, None).is_none());
+        // a generator's name runs the default part on what it parsed
+        assert_eq!(
+            find(2022, 16, 1, Some("RAYON")).unwrap().name,
+            Some("rayon")
+        );
+        assert!(!solvers()
+            .iter()
+            .any(|solver| solver.day == 16 && solver.name.is_some()));
+    }
+
+    #[test]
+    fn test_days() {
+        assert_eq!(years(), vec![2022]);
+        let registered = registry()
+            .into_keys()
+            .filter(|&(year, _)| year == 2022)
+            .map(|(_, day)| day)
+            .collect_vec();
+        assert_eq!(days(2022), registered);
+        assert!((1..=16).all(|day| registered.contains(&day)));
+        assert!(days(2021).is_empty());
+    }
+
+    #[test]
+    fn test_render() {
+        let input =
+            fs::read_to_string("test_input/2022/day10.txt").expect("Error reading test input file");
+        let frames = render(2022, 10, &input, &Params::default())
+            .unwrap()
+            .unwrap();
+        assert_eq!(frames.len(), 240);
+        assert_eq!(render(2022, 2, "A Y\n", &Params::default()), Ok(None));
+    }
+
+    #[test]
+    fn test_dump() {
+        let input =
+            fs::read_to_string("test_input/2022/day07.txt").expect("Error reading test input file");
+        let dumped = dump(2022, 7, &input, None, &Params::default()).unwrap();
+        assert_eq!(dumped["file_system"]["/a/e"], 584);
+        assert_eq!(dumped["params"]["at_most"], 100000);
+
+        let input =
+            fs::read_to_string("test_input/2022/day16.txt").expect("Error reading test input file");
+        let dumped = dump(2022, 16, &input, Some("rayon"), &Params::default()).unwrap();
+        assert_eq!(dumped["valves"]["BB"]["flow_rate"], 13);
+        assert_eq!(dumped["valves"]["BB"]["distances"]["JJ"], 3);
+
+        let dumped = dump(2022, 10, "noop\naddx 3\n", None, &Params::default()).unwrap();
+        assert_eq!(dumped, serde_json::json!([1, 1, 1]));
+    }
+
+    #[test]
+    fn test_decipher() {
+        let mut constraints = Params::default();
+        constraints.set("target", 15);
+        constraints.set("fixed", serde_json::json!({ "X": "rock" }));
+        // a guide saved on Windows reads the same
+        let input = "\u{feff}A Y\r\nB X\r\nC Z\r\n";
+        let readings = decipher(2022, 2, input, &Params::default(), &constraints)
+            .unwrap()
+            .unwrap();
+        assert_eq!(readings.len(), 1);
+        assert_eq!(readings[0].column, day02::Column::Moves);
+        assert_eq!(readings[0].mapping["Z"], "scissors");
+
+        constraints.set("limit", 1);
+        assert!(matches!(
+            decipher(2022, 2, input, &Params::default(), &constraints),
+            Err(Error::InvalidParams { day: 2, .. })
+        ));
+        assert_eq!(
+            decipher(2022, 3, "abcd\n", &Params::default(), &Params::default()),
+            Ok(None)
+        );
+    }
+
+    #[test]
+    fn test_run() {
+        let input = "A Y\nB X\nC Z\n";
+        let run = find(2022, 2, 1, None).unwrap().run(input).unwrap();
+        assert_eq!(run.answer, Answer::from(15u32));
+    }
+
+    #[test]
+    fn test_run_normalizes_input() {
+        let input = "\u{feff}A Y\r\nB X\r\n\t\r\nC Z\r\n";
+        let run = find(2022, 2, 1, None).unwrap().run(input).unwrap();
+        assert_eq!(run.answer, Answer::from(15u32));
+
+        let run = find(2022, 6, 1, None)
+            .unwrap()
+            .run("\u{feff}bvwbjplbgvbhsrlpgdmjqwftvncz\r\n");
+        assert_eq!(run.unwrap().answer, Answer::from(5usize));
+    }
+
+    #[test]
+    fn test_run_all() {
+        let inputs = BTreeMap::from([
+            ((2022, 2), "A Y\nB X\nC Z\n".to_string()),
+            ((2022, 6), "mjqjpqmgbljsphdztnvjfqwrcgsmlb\n".to_string()),
+        ]);
+        let results = run_all(&inputs)
+            .into_iter()
+            .map(|(solver, run)| {
+                let run = run.unwrap();
+                // the counters would mix up solvers running at the same time
+                assert_eq!((run.parse_alloc, run.solve_alloc), (None, None));
+                (solver.day, solver.part, run.answer)
+            })
+            .collect_vec();
+        assert_eq!(
+            results,
+            vec![
+                (2, 1, Answer::from(15u32)),
+                (2, 2, Answer::from(12u32)),
+                (6, 1, Answer::from(7usize)),
+                (6, 2, Answer::from(19usize)),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_run_spans() {
+        #[derive(Clone, Default)]
+        struct Buffer(Arc<Mutex<Vec<u8>>>);
+
+        impl io::Write for Buffer {
+            fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+                self.0.lock().unwrap().write(buf)
+            }
+
+            fn flush(&mut self) -> io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let buffer = Buffer::default();
+        let writer = buffer.clone();
+        let subscriber = tracing_subscriber::fmt()
+            .with_max_level(tracing::Level::DEBUG)
+            .with_ansi(false)
+            .with_writer(move || writer.clone())
+            .finish();
+        let input = fs::read_to_string("test_input/2022/day10.txt").expect("error reading file");
+        tracing::subscriber::with_default(subscriber, || {
+            find(2022, 10, 1, None).unwrap().run(&input).unwrap();
+        });
+
+        let output = String::from_utf8(buffer.0.lock().unwrap().clone()).unwrap();
+        assert!(output.contains(
+            "day{year=2022 day=10}:part{part=1}: advent_2022::y2022::day10: signal strength cycle=20 x=21"
+        ));
+    }
+
+    #[test]
+    fn test_run_error() {
+        let err = find(2022, 2, 1, None)
+            .unwrap()
+            .run("A Y\nB Q\n")
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "day 2, line 2, column 3: invalid move: \"Q\""
+        );
+    }
+}
@@ -0,0 +1,208 @@
+//! Scaffolding for a new day: the module from `src/template.rs`, its registration in the year's
+//! `mod.rs`, and the sample input its tests read.
+
+use std::{
+    fs, io,
+    path::{Path, PathBuf},
+};
+
+use crate::y2022;
+
+const TEMPLATE: &str = include_str!("template.rs");
+
+/// What to fill the new day's tests in with.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Stub {
+    /// The puzzle's sample input, written to `test_input/YYYY/dayNN.txt`.
+    pub sample: String,
+    /// Expected answers for the sample. Numbers keep the template's `u32` (or `u64` if they
+    /// don't fit), anything else makes the part return a `String`.
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+/// The template with `X` replaced by `day` and `YYYY` by `year`, and each part's return type and
+/// expected value set from `stub`. cargo-aoc only runs 2022, so other years don't get `#[aoc]`.
+pub fn module(year: u16, day: u8, stub: &Stub) -> String {
+    let template = if year == y2022::YEAR {
+        TEMPLATE.to_string()
+    } else {
+        TEMPLATE
+            .lines()
+            .filter(|line| !line.starts_with("#[aoc("))
+            .map(|line| format!("{line}\n"))
+            .collect()
+    };
+    let mut module = template
+        .replace("#[aoc(dayX,", &format!("#[aoc(day{day},"))
+        .replace("YYYY", &year.to_string())
+        .replace("dayX", &format!("day{day:02}"))
+        .replace("DayX", &format!("Day{day:02}"))
+        .replace("const DAY: u8 = X;", &format!("const DAY: u8 = {day};"));
+    for (part, expected) in [(1, &stub.part1), (2, &stub.part2)] {
+        let Some(expected) = expected else {
+            continue;
+        };
+        let (ty, value) = match expected.parse::<u64>() {
+            Ok(n) if u32::try_from(n).is_ok() => ("u32", n.to_string()),
+            Ok(n) => ("u64", n.to_string()),
+            Err(_) => ("String", format!("{expected:?}.to_string()")),
+        };
+        module = module
+            .replace(
+                &format!("fn part_{part}(_input: &str) -> error::Result<u32>"),
+                &format!("fn part_{part}(_input: &str) -> error::Result<{ty}>"),
+            )
+            .replace(
+                &format!("assert_eq!(part_{part}(&input), Ok(0));"),
+                &format!("assert_eq!(part_{part}(&input), Ok({value}));"),
+            );
+    }
+    module
+}
+
+fn exists(path: &Path) -> io::Error {
+    io::Error::new(
+        io::ErrorKind::AlreadyExists,
+        format!("{} already exists", path.display()),
+    )
+}
+
+/// `source` with `line` added among the lines that `matches` accepts, keeping them sorted.
+/// `None` if no line matches.
+fn insert_sorted(source: &str, line: &str, matches: impl Fn(&str) -> bool) -> Option<String> {
+    let lines: Vec<&str> = source.lines().collect();
+    let matching: Vec<usize> = (0..lines.len())
+        .filter(|&i| matches(lines[i].trim()))
+        .collect();
+    let last = *matching.last()?;
+    let at = matching
+        .into_iter()
+        .find(|&i| lines[i].trim() > line.trim())
+        .unwrap_or(last + 1);
+    let indent = &lines[last][..lines[last].len() - lines[last].trim_start().len()];
+    let mut updated = lines[..at].join("\n");
+    updated.push('\n');
+    updated.push_str(indent);
+    updated.push_str(line.trim());
+    updated.push('\n');
+    updated.push_str(&lines[at..].join("\n"));
+    updated.push('\n');
+    Some(updated)
+}
+
+/// Create day `day` of `year` in the crate at `root`, returning the files written or changed.
+/// The year's module must already exist. Nothing is touched if the day already has a module, a
+/// registration or a sample input.
+pub fn scaffold(root: &Path, year: u16, day: u8, stub: &Stub) -> io::Result<Vec<PathBuf>> {
+    let module_path = root.join(format!("src/y{year}/day{day:02}.rs"));
+    let sample_path = root.join(format!("test_input/{year}/day{day:02}.txt"));
+    let mod_path = root.join(format!("src/y{year}/mod.rs"));
+
+    for path in [&module_path, &sample_path] {
+        if path.exists() {
+            return Err(exists(path));
+        }
+    }
+    let module_line = format!("pub mod day{day:02};");
+    let register_line = format!("register::<day{day:02}::Day{day:02}>(),");
+    let year_mod = fs::read_to_string(&mod_path)?;
+    if year_mod
+        .lines()
+        .any(|line| line.trim() == module_line || line.trim() == register_line)
+    {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("day {day} of {year} is already registered"),
+        ));
+    }
+
+    let not_found = |what: &str| {
+        io::Error::new(
+            io::ErrorKind::InvalidData,
+            format!("no {what} in {} to add day {day} to", mod_path.display()),
+        )
+    };
+    let is_day_module = |line: &str| line.starts_with("pub mod day");
+    let year_mod = insert_sorted(&year_mod, &module_line, is_day_module)
+        .ok_or_else(|| not_found("`pub mod dayNN;` lines"))?;
+    let is_registration = |line: &str| line.starts_with("register::<day");
+    let year_mod = insert_sorted(&year_mod, &register_line, is_registration)
+        .ok_or_else(|| not_found("`register::<dayNN::DayNN>()` lines"))?;
+
+    fs::create_dir_all(root.join(format!("test_input/{year}")))?;
+    fs::write(&module_path, module(year, day, stub))?;
+    fs::write(&sample_path, &stub.sample)?;
+    fs::write(&mod_path, year_mod)?;
+    Ok(vec![module_path, sample_path, mod_path])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_module() {
+        let stub = Stub {
+            sample: String::new(),
+            part1: Some("24000".to_string()),
+            part2: Some("CMZ".to_string()),
+        };
+        let module = module(2022, 17, &stub);
+        assert!(
+            module.contains("#[aoc(day17, part1)]\nfn part_1(_input: &str) -> error::Result<u32>")
+        );
+        assert!(module.contains("fn part_2(_input: &str) -> error::Result<String>"));
+        assert!(module.contains("pub struct Day17;"));
+        assert!(module.contains("const DAY: u8 = 17;"));
+        assert!(module.contains("fs::read_to_string(\"test_input/2022/day17.txt\")"));
+        assert!(module.contains("assert_eq!(part_1(&input), Ok(24000));"));
+        assert!(module.contains("assert_eq!(part_2(&input), Ok(\"CMZ\".to_string()));"));
+        assert!(!module.contains('X'));
+
+        let module = self::module(2023, 1, &stub);
+        assert!(!module.contains("#[aoc("));
+        assert!(module.contains("fs::read_to_string(\"test_input/2023/day01.txt\")"));
+    }
+
+    #[test]
+    fn test_scaffold() {
+        let root = std::env::temp_dir().join(format!("advent-scaffold-{}", std::process::id()));
+        fs::create_dir_all(root.join("src/y2022")).unwrap();
+        fs::write(
+            root.join("src/y2022/mod.rs"),
+            "pub mod day01;\npub mod day10;\npub mod gen;\n\npub fn solutions() {\n    vec![\n        register::<day01::Day01>(),\n        register::<day10::Day10>(),\n    ]\n}\n",
+        )
+        .unwrap();
+
+        let stub = Stub {
+            sample: "1\n2\n".to_string(),
+            ..Stub::default()
+        };
+        let written = scaffold(&root, 2022, 3, &stub).unwrap();
+        assert_eq!(written.len(), 3);
+        assert_eq!(
+            fs::read_to_string(root.join("src/y2022/mod.rs")).unwrap(),
+            "pub mod day01;\npub mod day03;\npub mod day10;\npub mod gen;\n\npub fn solutions() {\n    vec![\n        register::<day01::Day01>(),\n        register::<day03::Day03>(),\n        register::<day10::Day10>(),\n    ]\n}\n"
+        );
+        assert_eq!(
+            fs::read_to_string(root.join("test_input/2022/day03.txt")).unwrap(),
+            "1\n2\n"
+        );
+        assert!(fs::read_to_string(root.join("src/y2022/day03.rs"))
+            .unwrap()
+            .contains("pub struct Day03;"));
+
+        let err = scaffold(&root, 2022, 3, &stub).unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::AlreadyExists);
+
+        scaffold(&root, 2022, 11, &stub).unwrap();
+        assert!(fs::read_to_string(root.join("src/y2022/mod.rs"))
+            .unwrap()
+            .contains("pub mod day10;\npub mod day11;\npub mod gen;"));
+
+        assert!(scaffold(&root, 2023, 1, &stub).is_err());
+
+        fs::remove_dir_all(&root).unwrap();
+    }
+}
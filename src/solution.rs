@@ -0,0 +1,256 @@
+use std::{any::Any, collections::BTreeMap, fmt, marker::PhantomData};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::{
+    error::{Error, Result},
+    params::Params,
+    util::parse::normalize,
+    viz::Frame,
+    y2022,
+};
+
+/// The answer to one part of a puzzle, whatever type the solver computed it in.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    Unsigned(u128),
+    Signed(i128),
+    Text(String),
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Answer::Unsigned(value) => write!(f, "{value}"),
+            Answer::Signed(value) => write!(f, "{value}"),
+            Answer::Text(value) => write!(f, "{value}"),
+        }
+    }
+}
+
+macro_rules! impl_from {
+    ($variant:ident, $target:ty, $($t:ty),*) => {
+        $(impl From<$t> for Answer {
+            fn from(value: $t) -> Self {
+                Answer::$variant(value as $target)
+            }
+        })*
+    };
+}
+
+impl_from!(Unsigned, u128, u8, u16, u32, u64, u128, usize);
+impl_from!(Signed, i128, i8, i16, i32, i64, i128, isize);
+
+impl From<String> for Answer {
+    fn from(value: String) -> Self {
+        Answer::Text(value)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(value: &str) -> Self {
+        Answer::Text(value.to_string())
+    }
+}
+
+/// An alternate implementation of one part, like `#[aoc(day3, part1, HashSet)]`.
+pub struct Alternate<I> {
+    pub part: u8,
+    pub name: &'static str,
+    pub run: fn(&I) -> Result<Answer>,
+}
+
+/// An alternate generator, selected by name. Alternates with the same name parse their input with
+/// it instead of [`Solution::parse`]; without one, the default parts solve what it parsed.
+pub struct Parser<I> {
+    pub name: &'static str,
+    pub parse: fn(&str, &Params) -> Result<I>,
+}
+
+/// A day's puzzle: a generator producing [`Solution::Input`] and the two parts solving it.
+pub trait Solution {
+    const DAY: u8;
+    /// Serializable so `advent dump-parsed` can show what the generator made of the input.
+    type Input: Serialize + 'static;
+
+    fn parse(input: &str) -> Result<Self::Input>;
+
+    /// Parse with puzzle parameters instead of the puzzle's own values. Days without any reject
+    /// every parameter.
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input> {
+        if !params.is_empty() {
+            return Err(Error::invalid_params(
+                Self::DAY,
+                "this day takes no parameters",
+            ));
+        }
+        Self::parse(input)
+    }
+    fn part1(input: &Self::Input) -> Result<Answer>;
+    fn part2(input: &Self::Input) -> Result<Answer>;
+
+    fn alternates() -> Vec<Alternate<Self::Input>> {
+        vec![]
+    }
+
+    fn parsers() -> Vec<Parser<Self::Input>> {
+        vec![]
+    }
+
+    /// Frames showing the puzzle, for days that implement [`crate::viz::Render`].
+    fn render(_input: &Self::Input) -> Option<Result<Vec<Frame>>> {
+        None
+    }
+}
+
+/// Object-safe view of a [`Solution`], so days with different input types can share a registry.
+pub trait DynSolution: Send + Sync {
+    fn day(&self) -> u8;
+    /// Every runnable `(part, name)` pair, with `None` naming the default solver.
+    fn variants(&self) -> Vec<(u8, Option<&'static str>)>;
+    /// Names of the alternate generators.
+    fn parsers(&self) -> Vec<&'static str>;
+    /// Parse `input` for the solver called `name`, using its named generator if there is one.
+    fn parse(&self, input: &str, name: Option<&str>, params: &Params) -> Result<Box<dyn Any>>;
+    /// Solve a part on input returned by [`DynSolution::parse`].
+    fn solve(&self, input: &dyn Any, part: u8, name: Option<&str>) -> Result<Answer>;
+    /// Visualize input returned by [`DynSolution::parse`], if the day can.
+    fn render(&self, input: &dyn Any) -> Option<Result<Vec<Frame>>>;
+    /// Input returned by [`DynSolution::parse`] as JSON.
+    fn dump(&self, input: &dyn Any) -> Value;
+}
+
+struct Registered<S>(PhantomData<fn() -> S>);
+
+impl<S: Solution> DynSolution for Registered<S> {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn variants(&self) -> Vec<(u8, Option<&'static str>)> {
+        let mut variants = vec![(1, None), (2, None)];
+        variants.extend(S::alternates().iter().map(|alt| (alt.part, Some(alt.name))));
+        variants.sort();
+        variants
+    }
+
+    fn parsers(&self) -> Vec<&'static str> {
+        S::parsers().iter().map(|parser| parser.name).collect()
+    }
+
+    fn parse(&self, input: &str, name: Option<&str>, params: &Params) -> Result<Box<dyn Any>> {
+        let parse = name
+            .and_then(|name| {
+                S::parsers()
+                    .into_iter()
+                    .find(|parser| parser.name.eq_ignore_ascii_case(name))
+            })
+            .map_or(
+                S::parse_with as fn(&str, &Params) -> Result<S::Input>,
+                |parser| parser.parse,
+            );
+        Ok(Box::new(parse(&normalize(input), params)?))
+    }
+
+    fn solve(&self, input: &dyn Any, part: u8, name: Option<&str>) -> Result<Answer> {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input was not parsed by this day");
+        let unknown = || Error::UnknownSolver {
+            day: S::DAY,
+            part,
+            name: name.map(str::to_string),
+        };
+        match (part, name) {
+            (1, None) => S::part1(input),
+            (2, None) => S::part2(input),
+            (part, Some(name)) => {
+                match S::alternates()
+                    .into_iter()
+                    .find(|alt| alt.part == part && alt.name.eq_ignore_ascii_case(name))
+                {
+                    Some(alt) => (alt.run)(input),
+                    None if self.parsers().iter().any(|p| p.eq_ignore_ascii_case(name)) => {
+                        self.solve(input, part, None)
+                    }
+                    None => Err(unknown()),
+                }
+            }
+            _ => Err(unknown()),
+        }
+    }
+
+    fn render(&self, input: &dyn Any) -> Option<Result<Vec<Frame>>> {
+        S::render(
+            input
+                .downcast_ref::<S::Input>()
+                .expect("input was not parsed by this day"),
+        )
+    }
+
+    fn dump(&self, input: &dyn Any) -> Value {
+        let input = input
+            .downcast_ref::<S::Input>()
+            .expect("input was not parsed by this day");
+        serde_json::to_value(input).expect("parsed inputs serialize to JSON")
+    }
+}
+
+/// `S` for a year's list of solutions, keyed by its day.
+pub fn register<S: Solution + 'static>() -> (u8, Box<dyn DynSolution>) {
+    (S::DAY, Box::new(Registered::<S>(PhantomData)))
+}
+
+/// Every implemented day, keyed by year and day.
+pub fn registry() -> BTreeMap<(u16, u8), Box<dyn DynSolution>> {
+    y2022::solutions()
+        .into_iter()
+        .map(|(day, solution)| ((y2022::YEAR, day), solution))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_answer_display() {
+        assert_eq!(Answer::from(24000u32).to_string(), "24000");
+        assert_eq!(Answer::from(-3isize).to_string(), "-3");
+        assert_eq!(Answer::from("CMZ").to_string(), "CMZ");
+    }
+
+    #[test]
+    fn test_registry() {
+        let registry = registry();
+        assert!((1..=16).all(|day| registry.contains_key(&(2022, day))));
+        assert!(registry
+            .iter()
+            .all(|((_, day), solution)| *day == solution.day()));
+
+        let day03 = &registry[&(2022, 3)];
+        assert_eq!(
+            day03.variants(),
+            vec![(1, None), (1, Some("HashSet")), (2, None)]
+        );
+
+        let input = fs::read_to_string("test_input/2022/day03.txt").expect("error reading file");
+        let input = day03.parse(&input, None, &Params::default()).unwrap();
+        assert_eq!(day03.solve(&*input, 1, None), Ok(Answer::from(157u32)));
+        assert_eq!(
+            day03.solve(&*input, 1, Some("hashset")),
+            Ok(Answer::from(157u32))
+        );
+        assert_eq!(
+            day03.solve(&*input, 2, Some("hashset")),
+            Err(Error::UnknownSolver {
+                day: 3,
+                part: 2,
+                name: Some("hashset".to_string())
+            })
+        );
+    }
+}
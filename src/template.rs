@@ -1,11 +1,41 @@
+use crate::{
+    error::{self, Error},
+    solution::{Answer, Solution},
+};
+
 #[aoc(dayX, part1)]
-fn part_1(_input: &str) -> u32 {
-    todo!();
+fn part_1(_input: &str) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        day: DayX::DAY,
+        part: 1,
+    })
 }
 
 #[aoc(dayX, part2)]
-fn part_2(_input: &str) -> u32 {
-    todo!();
+fn part_2(_input: &str) -> error::Result<u32> {
+    Err(Error::Unimplemented {
+        day: DayX::DAY,
+        part: 2,
+    })
+}
+
+pub struct DayX;
+
+impl Solution for DayX {
+    const DAY: u8 = X;
+    type Input = String;
+
+    fn parse(input: &str) -> error::Result<String> {
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &String) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &String) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
 }
 
 #[cfg(test)]
@@ -17,14 +47,14 @@ mod tests {
     #[test]
     fn test_part_1() {
         let input =
-            fs::read_to_string("test_input/dayX.txt").expect("Error reading test input file");
-        assert_eq!(part_1(&input), 0);
+            fs::read_to_string("test_input/YYYY/dayX.txt").expect("Error reading test input file");
+        assert_eq!(part_1(&input), Ok(0));
     }
 
     #[test]
     fn test_part_2() {
         let input =
-            fs::read_to_string("test_input/dayX.txt").expect("Error reading test input file");
-        assert_eq!(part_2(&input), 0);
+            fs::read_to_string("test_input/YYYY/dayX.txt").expect("Error reading test input file");
+        assert_eq!(part_2(&input), Ok(0));
     }
 }
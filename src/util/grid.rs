@@ -0,0 +1,364 @@
+use std::{
+    fmt,
+    ops::{Add, AddAssign, Sub},
+};
+
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+use crate::{
+    error::{self, Error},
+    util::parse::strip_bom,
+};
+
+/// A point on a grid. `y` grows downwards, like the puzzle drawings.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Default, Serialize)]
+pub struct Pos {
+    pub x: i32,
+    pub y: i32,
+}
+
+impl Pos {
+    pub const fn new(x: i32, y: i32) -> Self {
+        Pos { x, y }
+    }
+
+    pub fn step(self, dir: Dir) -> Pos {
+        self + dir.offset()
+    }
+
+    pub fn manhattan(self, other: Pos) -> u32 {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    /// The up to 4 orthogonally adjacent positions.
+    pub fn neighbours4(self) -> impl Iterator<Item = Pos> {
+        Dir::ALL.into_iter().map(move |dir| self.step(dir))
+    }
+
+    /// The 8 orthogonally and diagonally adjacent positions.
+    pub fn neighbours8(self) -> impl Iterator<Item = Pos> {
+        NEIGHBOURS_8.into_iter().map(move |offset| self + offset)
+    }
+}
+
+const NEIGHBOURS_8: [Pos; 8] = [
+    Pos::new(-1, -1),
+    Pos::new(0, -1),
+    Pos::new(1, -1),
+    Pos::new(-1, 0),
+    Pos::new(1, 0),
+    Pos::new(-1, 1),
+    Pos::new(0, 1),
+    Pos::new(1, 1),
+];
+
+impl From<(i32, i32)> for Pos {
+    fn from((x, y): (i32, i32)) -> Self {
+        Pos { x, y }
+    }
+}
+
+impl Add for Pos {
+    type Output = Pos;
+
+    fn add(self, rhs: Pos) -> Pos {
+        Pos::new(self.x + rhs.x, self.y + rhs.y)
+    }
+}
+
+impl AddAssign for Pos {
+    fn add_assign(&mut self, rhs: Pos) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Pos {
+    type Output = Pos;
+
+    fn sub(self, rhs: Pos) -> Pos {
+        Pos::new(self.x - rhs.x, self.y - rhs.y)
+    }
+}
+
+impl fmt::Display for Pos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "({}, {})", self.x, self.y)
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize)]
+pub enum Dir {
+    Up,
+    Down,
+    Left,
+    Right,
+}
+
+impl Dir {
+    pub const ALL: [Dir; 4] = [Dir::Up, Dir::Down, Dir::Left, Dir::Right];
+
+    pub fn offset(self) -> Pos {
+        match self {
+            Dir::Up => Pos::new(0, -1),
+            Dir::Down => Pos::new(0, 1),
+            Dir::Left => Pos::new(-1, 0),
+            Dir::Right => Pos::new(1, 0),
+        }
+    }
+
+    /// `U`, `D`, `L` or `R`, as used in rope motions.
+    pub fn from_char(c: char) -> Option<Dir> {
+        match c {
+            'U' => Some(Dir::Up),
+            'D' => Some(Dir::Down),
+            'L' => Some(Dir::Left),
+            'R' => Some(Dir::Right),
+            _ => None,
+        }
+    }
+}
+
+/// A dense, rectangular grid whose top left cell sits at `origin`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Grid<T> {
+    origin: Pos,
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+/// Serialized row by row, e.g. `{"origin": {"x": 0, "y": 0}, "rows": [[1, 2], [3, 4]]}`.
+impl<T: Serialize> Serialize for Grid<T> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let rows = if self.width == 0 {
+            vec![]
+        } else {
+            self.cells.chunks(self.width).collect()
+        };
+        let mut grid = serializer.serialize_struct("Grid", 2)?;
+        grid.serialize_field("origin", &self.origin)?;
+        grid.serialize_field("rows", &rows)?;
+        grid.end()
+    }
+}
+
+impl<T: Clone> Grid<T> {
+    pub fn new(width: usize, height: usize, fill: T) -> Self {
+        Grid::with_origin(Pos::default(), width, height, fill)
+    }
+
+    pub fn with_origin(origin: Pos, width: usize, height: usize, fill: T) -> Self {
+        Grid {
+            origin,
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+}
+
+impl<T> Grid<T> {
+    /// Build a grid from rows of characters, one cell per character. `cell` returns `None` for
+    /// characters it doesn't accept, which is reported as `reason` at that line and column.
+    pub fn from_chars(
+        day: u8,
+        input: &str,
+        reason: &str,
+        mut cell: impl FnMut(Pos, char) -> Option<T>,
+    ) -> error::Result<Self> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+        for (y, line) in strip_bom(input).trim().lines().enumerate() {
+            let line = line.trim_end();
+            let mut row_width = 0;
+            for (x, c) in line.chars().enumerate() {
+                let pos = Pos::new(x as i32, y as i32);
+                cells.push(cell(pos, c).ok_or_else(|| Error::parse(day, y + 1, x + 1, c, reason))?);
+                row_width += 1;
+            }
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(Error::parse(
+                        day,
+                        y + 1,
+                        1,
+                        line,
+                        format!("expected {width} cells in every row"),
+                    ))
+                }
+                _ => {}
+            }
+            height += 1;
+        }
+
+        Ok(Grid {
+            origin: Pos::default(),
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn origin(&self) -> Pos {
+        self.origin
+    }
+
+    /// The bottom right cell.
+    pub fn max(&self) -> Pos {
+        self.origin + Pos::new(self.width as i32 - 1, self.height as i32 - 1)
+    }
+
+    fn index(&self, pos: Pos) -> Option<usize> {
+        let Pos { x, y } = pos - self.origin;
+        if x < 0 || y < 0 || x as usize >= self.width || y as usize >= self.height {
+            return None;
+        }
+        Some(y as usize * self.width + x as usize)
+    }
+
+    pub fn contains(&self, pos: Pos) -> bool {
+        self.index(pos).is_some()
+    }
+
+    pub fn get(&self, pos: Pos) -> Option<&T> {
+        self.index(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Pos) -> Option<&mut T> {
+        self.index(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Replace the cell at `pos`, returning the old value, or `None` if `pos` is out of bounds.
+    pub fn set(&mut self, pos: Pos, value: T) -> Option<T> {
+        self.get_mut(pos).map(|cell| std::mem::replace(cell, value))
+    }
+
+    /// Every position in the grid, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Pos> {
+        let (origin, width) = (self.origin, self.width);
+        (0..self.width * self.height)
+            .map(move |i| origin + Pos::new((i % width) as i32, (i / width) as i32))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Pos, &T)> {
+        self.positions().zip(self.cells.iter())
+    }
+
+    /// In-bounds orthogonal neighbours of `pos`.
+    pub fn neighbours4(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbours4().filter(|&pos| self.contains(pos))
+    }
+
+    /// In-bounds orthogonal and diagonal neighbours of `pos`.
+    pub fn neighbours8(&self, pos: Pos) -> impl Iterator<Item = Pos> + '_ {
+        pos.neighbours8().filter(|&pos| self.contains(pos))
+    }
+
+    /// Cells of row `y`, left to right. Empty if `y` is out of bounds.
+    pub fn row(&self, y: i32) -> impl Iterator<Item = &T> {
+        let start = Pos::new(self.origin.x, y);
+        self.get(start)
+            .into_iter()
+            .chain(self.ray(start, Dir::Right).map(|(_, cell)| cell))
+    }
+
+    /// Cells of column `x`, top to bottom. Empty if `x` is out of bounds.
+    pub fn column(&self, x: i32) -> impl Iterator<Item = &T> {
+        let start = Pos::new(x, self.origin.y);
+        self.get(start)
+            .into_iter()
+            .chain(self.ray(start, Dir::Down).map(|(_, cell)| cell))
+    }
+
+    /// Cells from `from` (exclusive) towards `dir`, up to the edge of the grid.
+    pub fn ray(&self, from: Pos, dir: Dir) -> impl Iterator<Item = (Pos, &T)> {
+        let mut pos = from;
+        std::iter::from_fn(move || {
+            pos = pos.step(dir);
+            self.get(pos).map(|cell| (pos, cell))
+        })
+    }
+}
+
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, cell) in self.cells.iter().enumerate() {
+            if i > 0 && i % self.width == 0 {
+                writeln!(f)?;
+            }
+            write!(f, "{cell}")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    const SAMPLE: &str = "123\n456\n";
+
+    fn sample() -> Grid<u32> {
+        Grid::from_chars(0, SAMPLE, "not a digit", |_, c| c.to_digit(10)).unwrap()
+    }
+
+    #[test]
+    fn test_from_chars() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid.get(Pos::new(2, 1)), Some(&6));
+        assert_eq!(grid.get(Pos::new(3, 1)), None);
+        assert_eq!(grid.to_string(), "123\n456");
+
+        assert_eq!(
+            Grid::from_chars(0, "12\n4x\n", "not a digit", |_, c| c.to_digit(10)),
+            Err(Error::parse(0, 2, 2, "x", "not a digit"))
+        );
+        assert_eq!(
+            Grid::from_chars(0, "12\n456\n", "not a digit", |_, c| c.to_digit(10)),
+            Err(Error::parse(
+                0,
+                2,
+                1,
+                "456",
+                "expected 2 cells in every row"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_origin() {
+        let mut grid = Grid::with_origin(Pos::new(-2, 5), 3, 2, '.');
+        assert_eq!(grid.set(Pos::new(-2, 5), '#'), Some('.'));
+        assert_eq!(grid.set(Pos::new(1, 5), '#'), None);
+        assert_eq!(grid.max(), Pos::new(0, 6));
+        assert_eq!(grid.to_string(), "#..\n...");
+        assert_eq!(grid.positions().last(), Some(Pos::new(0, 6)));
+    }
+
+    #[test]
+    fn test_iterators() {
+        let grid = sample();
+        assert_eq!(grid.row(1).copied().collect_vec(), vec![4, 5, 6]);
+        assert_eq!(grid.column(2).copied().collect_vec(), vec![3, 6]);
+        assert_eq!(grid.row(2).count(), 0);
+        assert_eq!(
+            grid.ray(Pos::new(2, 1), Dir::Left).collect_vec(),
+            vec![(Pos::new(1, 1), &5), (Pos::new(0, 1), &4)]
+        );
+        assert_eq!(grid.neighbours4(Pos::new(0, 0)).count(), 2);
+        assert_eq!(grid.neighbours8(Pos::new(1, 0)).count(), 5);
+    }
+}
@@ -0,0 +1,66 @@
+//! Inclusive integer intervals, written `(start, end)` like the puzzles' `2-4` section ranges.
+
+use num_traits::CheckedAdd;
+
+/// Merge intervals sorted by start, joining ones that overlap or touch, e.g. `(1, 3)` and
+/// `(4, 6)` into `(1, 6)`. What's left between the merged intervals is uncovered. An interval
+/// ending at `T`'s maximum touches anything after it.
+pub fn merge<T>(sorted: impl IntoIterator<Item = (T, T)>) -> Vec<(T, T)>
+where
+    T: Copy + Ord + CheckedAdd + From<u8>,
+{
+    let mut merged: Vec<(T, T)> = vec![];
+    for (start, end) in sorted {
+        let touches = |last: &(T, T)| {
+            last.1
+                .checked_add(&T::from(1))
+                .is_none_or(|after| start <= after)
+        };
+        match merged.last_mut() {
+            Some(last) if touches(last) => last.1 = last.1.max(end),
+            _ => merged.push((start, end)),
+        }
+    }
+    merged
+}
+
+/// Whether `outer` covers all of `inner`.
+pub fn contains<T: Ord>(outer: (T, T), inner: (T, T)) -> bool {
+    outer.0 <= inner.0 && inner.1 <= outer.1
+}
+
+/// Whether `a` and `b` have any value in common.
+pub fn overlaps<T: Ord>(a: (T, T), b: (T, T)) -> bool {
+    a.0 <= b.1 && b.0 <= a.1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_merge() {
+        assert_eq!(
+            merge([(1, 3), (4, 6), (5, 5), (8, 9)]),
+            vec![(1, 6), (8, 9)]
+        );
+        assert_eq!(merge([(-2isize, 10), (0, 4)]), vec![(-2, 10)]);
+        assert_eq!(merge(Vec::<(u32, u32)>::new()), vec![]);
+        assert_eq!(
+            merge([(0u8, u8::MAX), (u8::MAX, u8::MAX)]),
+            vec![(0, u8::MAX)]
+        );
+        assert_eq!(
+            merge([(i64::MIN, 0), (1, i64::MAX), (i64::MAX, i64::MAX)]),
+            vec![(i64::MIN, i64::MAX)]
+        );
+    }
+
+    #[test]
+    fn test_contains_and_overlaps() {
+        assert!(contains((2, 8), (3, 7)));
+        assert!(!contains((3, 7), (2, 8)));
+        assert!(overlaps((5, 7), (7, 9)));
+        assert!(!overlaps((2, 4), (6, 8)));
+    }
+}
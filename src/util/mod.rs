@@ -0,0 +1,5 @@
+//! Helpers shared by every year's puzzles.
+
+pub mod grid;
+pub mod intervals;
+pub mod parse;
@@ -0,0 +1,140 @@
+use std::borrow::Cow;
+
+const BOM: char = '\u{feff}';
+
+/// Clean up puzzle input however it was saved: strip a UTF-8 byte order mark, turn CRLF (or lone
+/// CR) line endings into LF and empty out lines that only contain whitespace.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let input = strip_bom(input);
+    let has_cr = input.contains('\r');
+    let has_blank_whitespace = input.lines().any(|line| is_blank(line) && !line.is_empty());
+    if !has_cr && !has_blank_whitespace {
+        return Cow::Borrowed(input);
+    }
+
+    let mut normalized = String::with_capacity(input.len());
+    for line in input.replace("\r\n", "\n").replace('\r', "\n").split('\n') {
+        if !is_blank(line) {
+            normalized.push_str(line);
+        }
+        normalized.push('\n');
+    }
+    // split adds an empty piece after a trailing newline, which doesn't need one of its own
+    normalized.pop();
+    Cow::Owned(normalized)
+}
+
+pub fn strip_bom(input: &str) -> &str {
+    input.strip_prefix(BOM).unwrap_or(input)
+}
+
+fn is_blank(line: &str) -> bool {
+    line.trim().is_empty()
+}
+
+/// Non-blank lines of `input` paired with their 1-based line number.
+pub fn numbered_lines(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    strip_bom(input)
+        .lines()
+        .enumerate()
+        .map(|(i, line)| (i + 1, line))
+        .filter(|(_, line)| !is_blank(line))
+}
+
+/// A group of consecutive non-blank lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Record<'a> {
+    /// 1-based line number of the record's first line.
+    pub line: usize,
+    pub text: &'a str,
+}
+
+impl<'a> Record<'a> {
+    /// Lines of the record paired with their 1-based line number in the whole input.
+    pub fn lines(&self) -> impl Iterator<Item = (usize, &'a str)> {
+        let first = self.line;
+        self.text
+            .lines()
+            .enumerate()
+            .map(move |(i, line)| (first + i, line))
+    }
+}
+
+/// Split `input` into records separated by one or more blank lines, whatever the line endings
+/// and even if the blank lines contain stray whitespace.
+pub fn split_records(input: &str) -> Vec<Record<'_>> {
+    let input = strip_bom(input);
+    let mut records = vec![];
+    let mut current: Option<(usize, usize, usize)> = None;
+    let mut offset = 0;
+
+    for (i, raw_line) in input.split_inclusive('\n').enumerate() {
+        let line = raw_line.trim_end_matches(['\n', '\r']);
+        if is_blank(line) {
+            if let Some((line_no, start, end)) = current.take() {
+                records.push(Record {
+                    line: line_no,
+                    text: &input[start..end],
+                });
+            }
+        } else {
+            let end = offset + line.len();
+            match &mut current {
+                Some((_, _, current_end)) => *current_end = end,
+                None => current = Some((i + 1, offset, end)),
+            }
+        }
+        offset += raw_line.len();
+    }
+    if let Some((line_no, start, end)) = current {
+        records.push(Record {
+            line: line_no,
+            text: &input[start..end],
+        });
+    }
+
+    records
+}
+
+#[cfg(test)]
+mod tests {
+    use itertools::Itertools;
+
+    use super::*;
+
+    #[test]
+    fn test_normalize() {
+        assert_eq!(normalize("1\n2\n"), Cow::Borrowed("1\n2\n"));
+        assert_eq!(normalize("\u{feff}1\r\n2\r\n"), "1\n2\n");
+        assert_eq!(normalize("1\r\n \t\r\n2"), "1\n\n2");
+        assert_eq!(normalize("1\r2\r"), "1\n2\n");
+    }
+
+    #[test]
+    fn test_numbered_lines() {
+        let lines = numbered_lines("\u{feff}a\r\n  \r\nb\n").collect_vec();
+        assert_eq!(lines, vec![(1, "a"), (3, "b")]);
+    }
+
+    #[test]
+    fn test_split_records() {
+        let expected = vec![
+            Record {
+                line: 1,
+                text: "1000\n2000",
+            },
+            Record {
+                line: 4,
+                text: "3000",
+            },
+        ];
+        assert_eq!(split_records("1000\n2000\n\n3000\n"), expected);
+        assert_eq!(split_records("1000\n2000\n \n3000"), expected);
+
+        let crlf = split_records("\u{feff}1000\r\n2000\r\n\r\n\r\n3000\r\n");
+        assert_eq!(crlf.len(), 2);
+        assert_eq!(crlf[0].text, "1000\r\n2000");
+        assert_eq!(crlf[1].line, 5);
+        assert_eq!(crlf[1].lines().collect_vec(), vec![(5, "3000")]);
+    }
+}
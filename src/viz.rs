@@ -0,0 +1,295 @@
+//! Visualizations. Days that can draw their puzzle implement [`Render`], producing one frame for
+//! a still or several for an animation, and the writers here turn frames into terminal text, PPM
+//! or PNG images and animated GIFs.
+
+use std::{
+    collections::HashMap,
+    fmt,
+    io::{self, Write},
+    str::FromStr,
+};
+
+use crate::util::grid::Grid;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rgb(pub u8, pub u8, pub u8);
+
+impl Rgb {
+    pub const BLACK: Rgb = Rgb(0, 0, 0);
+    pub const WHITE: Rgb = Rgb(255, 255, 255);
+
+    /// The colour `t` of the way from `self` to `to`, with `t` clamped to `0.0..=1.0`.
+    pub fn mix(self, to: Rgb, t: f32) -> Rgb {
+        let t = t.clamp(0.0, 1.0);
+        let channel = |a: u8, b: u8| (a as f32 + (b as f32 - a as f32) * t).round() as u8;
+        Rgb(
+            channel(self.0, to.0),
+            channel(self.1, to.1),
+            channel(self.2, to.2),
+        )
+    }
+}
+
+/// One cell of a frame: a character for the terminal and a colour for images.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Cell {
+    pub c: char,
+    pub color: Rgb,
+}
+
+impl Cell {
+    pub const fn new(c: char, color: Rgb) -> Self {
+        Cell { c, color }
+    }
+}
+
+impl Default for Cell {
+    fn default() -> Self {
+        Cell::new(' ', Rgb::BLACK)
+    }
+}
+
+impl fmt::Display for Cell {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.c)
+    }
+}
+
+/// A picture of the puzzle. Displaying it gives the terminal version.
+pub type Frame = Grid<Cell>;
+
+pub trait Render {
+    /// The frames to show, in order. Animations should keep to a few hundred frames, skipping
+    /// steps if there are more.
+    fn frames(&self) -> Vec<Frame>;
+}
+
+/// Every `n`th item, plus the last one, so that at most about `limit` are kept.
+pub fn sample<T>(items: Vec<T>, limit: usize) -> Vec<T> {
+    let n = items.len().div_ceil(limit.max(1)).max(1);
+    let last = items.len().saturating_sub(1);
+    items
+        .into_iter()
+        .enumerate()
+        .filter(|(i, _)| i % n == 0 || *i == last)
+        .map(|(_, item)| item)
+        .collect()
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Format {
+    Ascii,
+    Ppm,
+    Png,
+    Gif,
+}
+
+impl Format {
+    pub fn extension(self) -> &'static str {
+        match self {
+            Format::Ascii => "txt",
+            Format::Ppm => "ppm",
+            Format::Png => "png",
+            Format::Gif => "gif",
+        }
+    }
+}
+
+impl FromStr for Format {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "ascii" | "txt" => Ok(Format::Ascii),
+            "ppm" => Ok(Format::Ppm),
+            "png" => Ok(Format::Png),
+            "gif" => Ok(Format::Gif),
+            _ => Err(format!(
+                "unknown format `{s}`, expected ascii, ppm, png or gif"
+            )),
+        }
+    }
+}
+
+/// Write `frames` as `format`. Text shows every frame, stills show the last one and GIFs play
+/// them all, each cell drawn as a `scale` by `scale` square and each frame shown for `delay`
+/// hundredths of a second.
+pub fn write(
+    out: &mut impl Write,
+    frames: &[Frame],
+    format: Format,
+    scale: usize,
+    delay: u16,
+) -> io::Result<()> {
+    let last = frames
+        .last()
+        .ok_or_else(|| io::Error::new(io::ErrorKind::InvalidInput, "no frames to write"))?;
+    match format {
+        Format::Ascii => write_ascii(out, frames),
+        Format::Ppm => write_ppm(out, last, scale),
+        Format::Png => write_png(out, last, scale),
+        Format::Gif => write_gif(out, frames, scale, delay),
+    }
+}
+
+/// Every frame as text, separated by blank lines.
+pub fn write_ascii(out: &mut impl Write, frames: &[Frame]) -> io::Result<()> {
+    for (i, frame) in frames.iter().enumerate() {
+        if i > 0 {
+            writeln!(out)?;
+        }
+        writeln!(out, "{frame}")?;
+    }
+    Ok(())
+}
+
+/// Pixels of `frame` row by row, each cell repeated `scale` times in both directions.
+fn pixels(frame: &Frame, scale: usize) -> impl Iterator<Item = Rgb> + '_ {
+    let colors: Vec<Rgb> = frame.iter().map(|(_, cell)| cell.color).collect();
+    let width = frame.width();
+    (0..frame.height() * scale).flat_map(move |y| {
+        let row = &colors[(y / scale) * width..(y / scale + 1) * width];
+        row.iter()
+            .flat_map(|&color| std::iter::repeat_n(color, scale))
+            .collect::<Vec<_>>()
+    })
+}
+
+fn size(frame: &Frame, scale: usize) -> (usize, usize) {
+    (frame.width() * scale, frame.height() * scale)
+}
+
+/// A binary PPM (`P6`) image.
+pub fn write_ppm(out: &mut impl Write, frame: &Frame, scale: usize) -> io::Result<()> {
+    let (width, height) = size(frame, scale);
+    write!(out, "P6\n{width} {height}\n255\n")?;
+    let bytes: Vec<u8> = pixels(frame, scale)
+        .flat_map(|Rgb(r, g, b)| [r, g, b])
+        .collect();
+    out.write_all(&bytes)
+}
+
+pub fn write_png(out: &mut impl Write, frame: &Frame, scale: usize) -> io::Result<()> {
+    let (width, height) = size(frame, scale);
+    let mut encoder = png::Encoder::new(out, width as u32, height as u32);
+    encoder.set_color(png::ColorType::Rgb);
+    encoder.set_depth(png::BitDepth::Eight);
+    let bytes: Vec<u8> = pixels(frame, scale)
+        .flat_map(|Rgb(r, g, b)| [r, g, b])
+        .collect();
+    encoder
+        .write_header()
+        .and_then(|mut writer| writer.write_image_data(&bytes))
+        .map_err(io::Error::other)
+}
+
+/// An animated GIF looping over `frames`, which must all be the same size and use at most 256
+/// colours between them.
+pub fn write_gif(
+    out: &mut impl Write,
+    frames: &[Frame],
+    scale: usize,
+    delay: u16,
+) -> io::Result<()> {
+    let invalid = |reason: &str| io::Error::new(io::ErrorKind::InvalidInput, reason.to_string());
+    let first = frames
+        .first()
+        .ok_or_else(|| invalid("no frames to write"))?;
+    let (width, height) = size(first, scale);
+    if frames
+        .iter()
+        .any(|frame| size(frame, scale) != (width, height))
+    {
+        return Err(invalid("frames differ in size"));
+    }
+    let (width, height) = (
+        u16::try_from(width).map_err(|_| invalid("frames too wide for a GIF"))?,
+        u16::try_from(height).map_err(|_| invalid("frames too tall for a GIF"))?,
+    );
+
+    let mut palette: HashMap<Rgb, u8> = HashMap::new();
+    let mut colors = vec![];
+    let mut indexed = vec![];
+    for frame in frames {
+        let mut pixels_of_frame = vec![];
+        for color in pixels(frame, scale) {
+            let next = palette.len();
+            let index = match palette.get(&color) {
+                Some(&index) => index,
+                None => {
+                    let index = u8::try_from(next)
+                        .map_err(|_| invalid("frames use more than 256 colours"))?;
+                    palette.insert(color, index);
+                    colors.extend([color.0, color.1, color.2]);
+                    index
+                }
+            };
+            pixels_of_frame.push(index);
+        }
+        indexed.push(pixels_of_frame);
+    }
+
+    let mut encoder = gif::Encoder::new(out, width, height, &colors).map_err(io::Error::other)?;
+    encoder
+        .set_repeat(gif::Repeat::Infinite)
+        .map_err(io::Error::other)?;
+    for pixels in indexed {
+        let mut frame = gif::Frame::from_indexed_pixels(width, height, pixels, None);
+        frame.delay = delay;
+        encoder.write_frame(&frame).map_err(io::Error::other)?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::util::grid::Pos;
+
+    fn frame() -> Frame {
+        let mut frame = Grid::new(2, 1, Cell::new('.', Rgb::BLACK));
+        frame.set(Pos::new(1, 0), Cell::new('#', Rgb(255, 0, 0)));
+        frame
+    }
+
+    #[test]
+    fn test_sample() {
+        assert_eq!(sample((0..10).collect(), 4), vec![0, 3, 6, 9]);
+        assert_eq!(sample((0..11).collect(), 4), vec![0, 3, 6, 9, 10]);
+        assert_eq!(sample((0..3).collect(), 4), vec![0, 1, 2]);
+    }
+
+    #[test]
+    fn test_ascii() {
+        let mut out = vec![];
+        write(&mut out, &[frame(), frame()], Format::Ascii, 1, 0).unwrap();
+        assert_eq!(String::from_utf8(out).unwrap(), ".#\n\n.#\n");
+        assert_eq!("PNG".parse(), Ok(Format::Png));
+        assert!("jpeg".parse::<Format>().is_err());
+    }
+
+    #[test]
+    fn test_ppm() {
+        let mut out = vec![];
+        write_ppm(&mut out, &frame(), 2).unwrap();
+        let header = b"P6\n4 2\n255\n";
+        assert_eq!(&out[..header.len()], header);
+        let row = [0, 0, 0, 0, 0, 0, 255, 0, 0, 255, 0, 0];
+        assert_eq!(&out[header.len()..], [row, row].concat());
+    }
+
+    #[test]
+    fn test_png_and_gif() {
+        let mut out = vec![];
+        write_png(&mut out, &frame(), 3).unwrap();
+        assert_eq!(&out[..8], b"\x89PNG\r\n\x1a\n");
+
+        let mut out = vec![];
+        write_gif(&mut out, &[frame(), frame()], 3, 10).unwrap();
+        assert_eq!(&out[..6], b"GIF89a");
+
+        let wide = Grid::new(3, 1, Cell::default());
+        assert!(write_gif(&mut vec![], &[frame(), wide], 1, 10).is_err());
+        assert!(write(&mut vec![], &[], Format::Png, 1, 10).is_err());
+    }
+}
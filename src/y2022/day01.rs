@@ -0,0 +1,631 @@
+use std::{cmp::Reverse, collections::BinaryHeap, fmt, io::BufRead};
+
+use itertools::{process_results, Itertools};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{self, Error},
+    params,
+    solution::{Answer, Solution},
+    util::parse::{split_records, strip_bom},
+};
+
+/// The integer type calories are added up in. Items always fit in a `u32`, but a long list of
+/// them may not.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Width {
+    #[default]
+    U32,
+    U64,
+    U128,
+}
+
+impl Width {
+    pub fn max(self) -> u128 {
+        match self {
+            Width::U32 => u32::MAX.into(),
+            Width::U64 => u64::MAX.into(),
+            Width::U128 => u128::MAX,
+        }
+    }
+
+    /// `a + b`, or `None` if it doesn't fit.
+    pub fn add(self, a: u128, b: u128) -> Option<u128> {
+        a.checked_add(b).filter(|&sum| sum <= self.max())
+    }
+}
+
+impl fmt::Display for Width {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Width::U32 => write!(f, "u32"),
+            Width::U64 => write!(f, "u64"),
+            Width::U128 => write!(f, "u128"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// What to add up calories in. A total that doesn't fit is an error rather than wrapping.
+    pub width: Width,
+}
+
+fn parse_item(line_no: usize, item: &str) -> error::Result<u32> {
+    item.trim()
+        .parse()
+        .map_err(|_| Error::parse(Day01::DAY, line_no, 1, item, "invalid calories"))
+}
+
+/// Add `calories` from `line_no` to the total of the elf at `index`.
+fn add_item(
+    width: Width,
+    index: usize,
+    total: u128,
+    (line_no, item): (usize, &str),
+    calories: u32,
+) -> error::Result<u128> {
+    width.add(total, calories.into()).ok_or_else(|| {
+        Error::parse(
+            Day01::DAY,
+            line_no,
+            1,
+            item,
+            format!("elf {index} carries more calories than fit in {width}"),
+        )
+    })
+}
+
+/// One elf's pack: the calories of every item it carries.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Pack {
+    /// Position in the input, counting from 0.
+    pub index: usize,
+    /// 1-based line of the first item.
+    pub line: usize,
+    pub items: Vec<u32>,
+    pub total: u128,
+}
+
+/// Every elf's pack, in input order.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize)]
+pub struct Inventory {
+    pub packs: Vec<Pack>,
+    /// Every total fits in this.
+    pub width: Width,
+}
+
+impl Inventory {
+    pub fn parse(input: &str, width: Width) -> error::Result<Inventory> {
+        let packs = split_records(input)
+            .iter()
+            .enumerate()
+            .map(|(index, record)| {
+                let mut items = vec![];
+                let mut total = 0;
+                for (line_no, item) in record.lines() {
+                    let calories = parse_item(line_no, item)?;
+                    total = add_item(width, index, total, (line_no, item), calories)?;
+                    items.push(calories);
+                }
+                Ok(Pack {
+                    index,
+                    line: record.line,
+                    items,
+                    total,
+                })
+            })
+            .collect::<error::Result<_>>()?;
+        Ok(Inventory { packs, width })
+    }
+
+    pub fn len(&self) -> usize {
+        self.packs.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.packs.is_empty()
+    }
+
+    pub fn elves(&self) -> impl Iterator<Item = Elf> + '_ {
+        self.packs.iter().map(|pack| Elf {
+            index: pack.index,
+            total: pack.total,
+        })
+    }
+
+    /// Every elf, most calories first. Ties keep input order.
+    pub fn ranked(&self) -> Vec<Elf> {
+        best_elves(self.elves(), self.len())
+    }
+
+    /// The 1-based rank of the elf at `index`, shared by elves with the same total, or `None` if
+    /// there is no such elf.
+    pub fn rank(&self, index: usize) -> Option<usize> {
+        let total = self.packs.get(index)?.total;
+        Some(1 + self.elves().filter(|elf| elf.total > total).count())
+    }
+
+    /// The elves carrying more than `threshold` calories, in input order.
+    pub fn above(&self, threshold: u128) -> Vec<Elf> {
+        self.elves().filter(|elf| elf.total > threshold).collect()
+    }
+
+    fn sorted_totals(&self) -> Vec<u128> {
+        self.elves().map(|elf| elf.total).sorted().collect()
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        let sum: f64 = self.elves().map(|elf| elf.total as f64).sum();
+        (!self.is_empty()).then(|| sum / self.len() as f64)
+    }
+
+    /// The middle total, or the mean of the two middle ones for an even number of elves.
+    pub fn median(&self) -> Option<f64> {
+        let totals = self.sorted_totals();
+        let mid = totals.len() / 2;
+        match totals.len() {
+            0 => None,
+            n if n % 2 == 1 => Some(totals[mid] as f64),
+            _ => Some((totals[mid - 1] as f64 + totals[mid] as f64) / 2.0),
+        }
+    }
+
+    /// The smallest total that at least `percent` percent of the elves carry no more than, by
+    /// the nearest-rank method. `None` without elves or for a percentage outside 0 to 100.
+    pub fn percentile(&self, percent: f64) -> Option<u128> {
+        if !(0.0..=100.0).contains(&percent) {
+            return None;
+        }
+        let totals = self.sorted_totals();
+        let rank = (percent / 100.0 * totals.len() as f64).ceil() as usize;
+        totals.get(rank.max(1) - 1).copied()
+    }
+
+    /// How many elves' totals fall in each `width` calories wide bucket, from the lowest total's
+    /// bucket to the highest's, empty buckets included.
+    pub fn histogram(&self, width: u32) -> Vec<Bucket> {
+        let width = u128::from(width.max(1));
+        let totals = self.sorted_totals();
+        let (Some(&min), Some(&max)) = (totals.first(), totals.last()) else {
+            return vec![];
+        };
+        let counts = totals.iter().counts_by(|&total| total / width);
+        (min / width..=max / width)
+            .map(|bucket| Bucket {
+                start: bucket * width,
+                end: (bucket + 1).saturating_mul(width),
+                count: counts.get(&bucket).copied().unwrap_or(0),
+            })
+            .collect()
+    }
+
+    /// Totals, statistics, the best `top` elves and a histogram with `width` calorie buckets.
+    pub fn summary(&self, top: usize, width: u32) -> Summary {
+        let totals = self.sorted_totals();
+        Summary {
+            elves: self.len(),
+            items: self.packs.iter().map(|pack| pack.items.len()).sum(),
+            calories: totals
+                .iter()
+                .try_fold(0u128, |sum, &total| sum.checked_add(total)),
+            min: totals.first().copied(),
+            max: totals.last().copied(),
+            mean: self.mean(),
+            median: self.median(),
+            percentiles: [25, 75, 90]
+                .into_iter()
+                .filter_map(|p| Some((p, self.percentile(f64::from(p))?)))
+                .collect(),
+            top: best_elves(self.elves(), top),
+            histogram: self.histogram(width),
+        }
+    }
+}
+
+/// Elves whose totals are at least `start` and less than `end`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Bucket {
+    pub start: u128,
+    pub end: u128,
+    pub count: usize,
+}
+
+/// An overview of an [`Inventory`], printable or serializable.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct Summary {
+    pub elves: usize,
+    pub items: usize,
+    /// `None` if the total of every elf doesn't fit in a `u128`.
+    pub calories: Option<u128>,
+    pub min: Option<u128>,
+    pub max: Option<u128>,
+    pub mean: Option<f64>,
+    pub median: Option<f64>,
+    /// `(percent, total)` pairs.
+    pub percentiles: Vec<(u8, u128)>,
+    pub top: Vec<Elf>,
+    pub histogram: Vec<Bucket>,
+}
+
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let calories = match self.calories {
+            Some(calories) => calories.to_string(),
+            None => format!("over {}", u128::MAX),
+        };
+        writeln!(
+            f,
+            "{} elves carrying {} items, {calories} calories",
+            self.elves, self.items
+        )?;
+        let (Some(min), Some(max), Some(mean), Some(median)) =
+            (self.min, self.max, self.mean, self.median)
+        else {
+            return Ok(());
+        };
+        writeln!(f, "min {min}, median {median}, mean {mean:.1}, max {max}")?;
+        let percentiles = self
+            .percentiles
+            .iter()
+            .map(|(percent, total)| format!("p{percent} {total}"))
+            .join(", ");
+        writeln!(f, "{percentiles}")?;
+        for (rank, elf) in self.top.iter().enumerate() {
+            writeln!(f, "#{} elf {}: {}", rank + 1, elf.index, elf.total)?;
+        }
+        let most = self.histogram.iter().map(|b| b.count).max().unwrap_or(0);
+        for bucket in &self.histogram {
+            // bars are at most 40 wide, and an occupied bucket always shows
+            let bar = (bucket.count * 40).div_ceil(most.max(1));
+            writeln!(
+                f,
+                "{:>8}..{:<8} {:>5} {}",
+                bucket.start,
+                bucket.end,
+                bucket.count,
+                "#".repeat(bar)
+            )?;
+        }
+        Ok(())
+    }
+}
+
+/// An elf, by position in the input counting from 0, and the calories it carries.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+pub struct Elf {
+    pub index: usize,
+    pub total: u128,
+}
+
+/// The elves in `reader`, read a line at a time, so memory doesn't grow with the input. Lines
+/// can end in LF or CRLF, and totals are added up in `width`.
+pub fn read_elves<R: BufRead>(reader: R, width: Width) -> ReadElves<R> {
+    ReadElves {
+        reader,
+        width,
+        line: String::new(),
+        line_no: 0,
+        index: 0,
+        done: false,
+    }
+}
+
+/// Iterator returned by [`read_elves`]. Stops after the first error.
+pub struct ReadElves<R> {
+    reader: R,
+    width: Width,
+    line: String,
+    line_no: usize,
+    index: usize,
+    done: bool,
+}
+
+impl<R: BufRead> ReadElves<R> {
+    fn elf(&mut self, total: u128) -> Elf {
+        let elf = Elf {
+            index: self.index,
+            total,
+        };
+        self.index += 1;
+        elf
+    }
+}
+
+impl<R: BufRead> Iterator for ReadElves<R> {
+    type Item = error::Result<Elf>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+        let mut current: Option<u128> = None;
+        loop {
+            self.line.clear();
+            match self.reader.read_line(&mut self.line) {
+                Ok(0) => {
+                    self.done = true;
+                    return current.map(|total| Ok(self.elf(total)));
+                }
+                Ok(_) => self.line_no += 1,
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(Error::Io {
+                        day: Day01::DAY,
+                        reason: err.to_string(),
+                    }));
+                }
+            }
+            let mut item = self.line.strip_suffix('\n').unwrap_or(&self.line);
+            item = item.strip_suffix('\r').unwrap_or(item);
+            if self.line_no == 1 {
+                item = strip_bom(item);
+            }
+            if item.trim().is_empty() {
+                match current {
+                    Some(total) => return Some(Ok(self.elf(total))),
+                    None => continue,
+                }
+            }
+            let total = parse_item(self.line_no, item).and_then(|calories| {
+                let total = current.unwrap_or(0);
+                add_item(
+                    self.width,
+                    self.index,
+                    total,
+                    (self.line_no, item),
+                    calories,
+                )
+            });
+            match total {
+                Ok(total) => current = Some(total),
+                Err(err) => {
+                    self.done = true;
+                    return Some(Err(err));
+                }
+            }
+        }
+    }
+}
+
+/// The `k` elves carrying the most calories, most first, keeping only `k` of them at a time.
+/// Ties go to the elf listed first.
+pub fn best_elves(elves: impl IntoIterator<Item = Elf>, k: usize) -> Vec<Elf> {
+    // a min-heap, so the worst of the best is the one to drop
+    let mut best = BinaryHeap::with_capacity(k + 1);
+    for elf in elves {
+        best.push(Reverse((elf.total, Reverse(elf.index))));
+        if best.len() > k {
+            best.pop();
+        }
+    }
+    best.into_sorted_vec()
+        .into_iter()
+        .map(|Reverse((total, Reverse(index)))| Elf { index, total })
+        .collect()
+}
+
+/// The `k` elves carrying the most calories in `reader`, in O(k) memory.
+pub fn top_elves(reader: impl BufRead, k: usize, width: Width) -> error::Result<Vec<Elf>> {
+    process_results(read_elves(reader, width), |elves| best_elves(elves, k))
+}
+
+/// The sum of the elves' totals, or an error naming them if it doesn't fit in `width`.
+fn sum_elves(elves: &[Elf], width: Width) -> error::Result<u128> {
+    elves
+        .iter()
+        .try_fold(0, |sum, elf| width.add(sum, elf.total))
+        .ok_or_else(|| {
+            let indexes = elves.iter().map(|elf| elf.index).join(", ");
+            Error::no_solution(
+                Day01::DAY,
+                format!("elves {indexes} carry more calories than fit in {width}"),
+            )
+        })
+}
+
+#[aoc_generator(day1)]
+fn generator(input: &str) -> error::Result<Inventory> {
+    Inventory::parse(input, Width::default())
+}
+
+#[aoc(day1, part1)]
+fn part_1(input: &Inventory) -> error::Result<u128> {
+    input
+        .elves()
+        .map(|elf| elf.total)
+        .max()
+        .ok_or_else(|| Error::no_solution(Day01::DAY, "no elves in input"))
+}
+
+#[aoc(day1, part2)]
+fn part_2(input: &Inventory) -> error::Result<u128> {
+    sum_elves(&best_elves(input.elves(), 3), input.width)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+    type Input = Inventory;
+
+    fn parse(input: &str) -> error::Result<Inventory> {
+        generator(input)
+    }
+
+    fn parse_with(input: &str, params: &params::Params) -> error::Result<Inventory> {
+        let params: Params = params.get(Self::DAY)?;
+        Inventory::parse(input, params.width)
+    }
+
+    fn part1(input: &Inventory) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Inventory) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input = fs::read_to_string("test_input/2022/day01.txt").expect("Error reading file");
+
+        assert_eq!(part_1(&generator(&input).unwrap()), Ok(24000));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = fs::read_to_string("test_input/2022/day01.txt").expect("Error reading file");
+
+        assert_eq!(part_2(&generator(&input).unwrap()), Ok(45000));
+    }
+
+    #[test]
+    fn test_crlf_and_whitespace() {
+        let input = generator("1000\r\n2000\r\n  \r\n3000\r\n\r\n4000\r\n").unwrap();
+        assert_eq!(part_1(&input), Ok(4000));
+        assert_eq!(part_2(&input), Ok(10000));
+    }
+
+    #[test]
+    fn test_top_elves() {
+        let input = fs::read_to_string("test_input/2022/day01.txt").expect("Error reading file");
+        let best = top_elves(input.as_bytes(), 3, Width::U32).unwrap();
+        assert_eq!(
+            best,
+            vec![
+                Elf {
+                    index: 3,
+                    total: 24000
+                },
+                Elf {
+                    index: 2,
+                    total: 11000
+                },
+                Elf {
+                    index: 4,
+                    total: 10000
+                },
+            ]
+        );
+        assert_eq!(top_elves(input.as_bytes(), 0, Width::U32), Ok(vec![]));
+        assert_eq!(
+            top_elves(input.as_bytes(), 10, Width::U32).unwrap().len(),
+            5
+        );
+
+        let crlf = "\u{feff}1000\r\n2000\r\n  \r\n3000\r\n\r\n\r\n3000\r\n";
+        let totals = read_elves(crlf.as_bytes(), Width::U32)
+            .map(|elf| elf.map(|elf| elf.total))
+            .collect::<error::Result<Vec<_>>>();
+        assert_eq!(totals, Ok(vec![3000, 3000, 3000]));
+        // ties go to the first elf
+        assert_eq!(
+            top_elves(crlf.as_bytes(), 1, Width::U32).unwrap()[0].index,
+            0
+        );
+        assert_eq!(
+            top_elves("1000\n\n3000\nlots\n".as_bytes(), 3, Width::U32),
+            Err(Error::parse(1, 4, 1, "lots", "invalid calories"))
+        );
+    }
+
+    #[test]
+    fn test_inventory() {
+        let input = fs::read_to_string("test_input/2022/day01.txt").expect("Error reading file");
+        let inventory = generator(&input).unwrap();
+        assert_eq!(inventory.len(), 5);
+        assert_eq!(inventory.packs[1].items, vec![4000]);
+        assert_eq!(inventory.packs[3].line, 10);
+        assert_eq!(
+            inventory.ranked().iter().map(|elf| elf.index).collect_vec(),
+            vec![3, 2, 4, 0, 1]
+        );
+        assert_eq!(inventory.rank(0), Some(4));
+        assert_eq!(inventory.rank(5), None);
+        assert_eq!(
+            inventory
+                .above(10000)
+                .iter()
+                .map(|elf| elf.index)
+                .collect_vec(),
+            vec![2, 3]
+        );
+        assert_eq!(inventory.mean(), Some(11000.0));
+        assert_eq!(inventory.median(), Some(10000.0));
+        assert_eq!(inventory.percentile(0.0), Some(4000));
+        assert_eq!(inventory.percentile(50.0), Some(10000));
+        assert_eq!(inventory.percentile(100.0), Some(24000));
+        assert_eq!(inventory.percentile(101.0), None);
+        assert_eq!(
+            inventory
+                .histogram(10000)
+                .iter()
+                .map(|bucket| (bucket.start, bucket.count))
+                .collect_vec(),
+            vec![(0, 2), (10000, 2), (20000, 1)]
+        );
+
+        let summary = inventory.summary(3, 10000);
+        assert_eq!(summary.calories, Some(55000));
+        assert_eq!(summary.top[0].total, 24000);
+        let printed = summary.to_string();
+        assert!(printed.starts_with("5 elves carrying 10 items, 55000 calories\n"));
+        assert!(printed.contains("#1 elf 3: 24000\n"));
+        assert_eq!(
+            serde_json::to_value(&summary).unwrap()["percentiles"],
+            serde_json::json!([[25, 6000], [75, 11000], [90, 24000]])
+        );
+
+        let empty = Inventory::default().summary(3, 1000);
+        assert_eq!(empty.to_string(), "0 elves carrying 0 items, 0 calories\n");
+    }
+
+    #[test]
+    fn test_invalid_item() {
+        let input = "1000\n2000\n\n3000\nlots\n";
+        assert_eq!(
+            generator(input),
+            Err(Error::parse(1, 5, 1, "lots", "invalid calories"))
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let input = "5\n\n4294967295\n1\n";
+        let overflow = Error::parse(1, 4, 1, "1", "elf 1 carries more calories than fit in u32");
+        assert_eq!(generator(input), Err(overflow.clone()));
+        assert_eq!(top_elves(input.as_bytes(), 1, Width::U32), Err(overflow));
+
+        let mut params = params::Params::default();
+        params.set("width", "u64");
+        let wide = Day01::parse_with(input, &params).unwrap();
+        assert_eq!(part_1(&wide), Ok(4294967296));
+        assert_eq!(part_2(&wide), Ok(4294967301));
+        assert_eq!(
+            top_elves(input.as_bytes(), 1, Width::U128).unwrap()[0].total,
+            4294967296
+        );
+
+        // every elf fits, but not all three together
+        let input = generator("4294967295\n\n1\n").unwrap();
+        assert_eq!(part_1(&input), Ok(4294967295));
+        assert_eq!(
+            part_2(&input),
+            Err(Error::no_solution(
+                1,
+                "elves 0, 1 carry more calories than fit in u32"
+            ))
+        );
+        assert_eq!(Width::U128.add(u128::MAX, 1), None);
+    }
+}
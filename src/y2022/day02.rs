@@ -0,0 +1,775 @@
+use std::{cmp::Reverse, collections::BTreeMap};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{self, column_of, Error},
+    params,
+    solution::{Answer, Solution},
+    util::parse::{normalize, numbered_lines},
+};
+
+/// How a round went for me.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Outcome {
+    Loss,
+    Draw,
+    Win,
+}
+
+impl Outcome {
+    const ALL: [Outcome; 3] = [Outcome::Loss, Outcome::Draw, Outcome::Win];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Outcome::Loss => "loss",
+            Outcome::Draw => "draw",
+            Outcome::Win => "win",
+        }
+    }
+}
+
+/// A move, the letters the two columns of the strategy guide use for it, and what playing it
+/// scores.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct Move {
+    pub name: String,
+    /// Letter for the elf playing it.
+    pub elf: String,
+    /// Letter for me playing it, in part 1.
+    pub me: String,
+    pub score: u32,
+}
+
+/// The letter the second column uses for an outcome in part 2, and what it scores.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(deny_unknown_fields)]
+pub struct OutcomeRule {
+    pub letter: String,
+    pub score: u32,
+}
+
+/// A game, loaded from a params file. The puzzle's Rock Paper Scissors is the default.
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Rules {
+    pub moves: Vec<Move>,
+    /// Loss, draw and win, in that order.
+    pub outcomes: [OutcomeRule; 3],
+    /// `payoff[me][elf]` is how playing `me` against `elf` goes. Without it the game is cyclic:
+    /// a move beats the moves an odd number of places before it, wrapping around, which needs an
+    /// odd number of moves. Rock Paper Scissors Lizard Spock is cyclic in the order rock, paper,
+    /// scissors, spock, lizard.
+    pub payoff: Option<Vec<Vec<Outcome>>>,
+}
+
+impl Default for Rules {
+    fn default() -> Self {
+        let moves = [
+            ("rock", "A", "X"),
+            ("paper", "B", "Y"),
+            ("scissors", "C", "Z"),
+        ];
+        let outcome = |letter: &str, score| OutcomeRule {
+            letter: letter.to_string(),
+            score,
+        };
+        Rules {
+            moves: (1..)
+                .zip(moves)
+                .map(|(score, (name, elf, me))| Move {
+                    name: name.to_string(),
+                    elf: elf.to_string(),
+                    me: me.to_string(),
+                    score,
+                })
+                .collect(),
+            outcomes: [outcome("X", 0), outcome("Y", 3), outcome("Z", 6)],
+            payoff: None,
+        }
+    }
+}
+
+/// A round of the strategy guide. Part 1 reads the second column as my move and part 2 as how
+/// the round ends, and its letter can mean either or both.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Round {
+    pub elf: usize,
+    pub me: Option<usize>,
+    pub outcome: Option<Outcome>,
+    /// The second column's letter and where it is in the guide.
+    pub letter: String,
+    pub line: usize,
+    pub column: usize,
+}
+
+impl Round {
+    fn invalid(&self, reason: &str) -> Error {
+        Error::parse(Day02::DAY, self.line, self.column, &self.letter, reason)
+    }
+}
+
+/// [`Rules`] checked and turned into a payoff table.
+#[derive(Debug, Clone, Serialize)]
+pub struct Game {
+    pub rules: Rules,
+    payoff: Vec<Vec<Outcome>>,
+}
+
+impl Game {
+    pub fn new(rules: Rules) -> Result<Game, String> {
+        let n = rules.moves.len();
+        if n == 0 {
+            return Err("a game needs at least one move".to_string());
+        }
+        let columns = [
+            rules
+                .moves
+                .iter()
+                .map(|m| m.elf.as_str())
+                .collect::<Vec<_>>(),
+            rules.moves.iter().map(|m| m.me.as_str()).collect(),
+            rules.outcomes.iter().map(|o| o.letter.as_str()).collect(),
+        ];
+        for column in columns {
+            for (i, letter) in column.iter().enumerate() {
+                if letter.is_empty() || letter.contains(char::is_whitespace) {
+                    return Err(format!("letter `{letter}` can't be blank or have spaces"));
+                }
+                if column[..i].contains(letter) {
+                    return Err(format!("letter `{letter}` is used twice in a column"));
+                }
+            }
+        }
+        let payoff = match rules.payoff.clone() {
+            Some(payoff) => {
+                if payoff.len() != n || payoff.iter().any(|row| row.len() != n) {
+                    return Err(format!("the payoff matrix must be {n} by {n}"));
+                }
+                payoff
+            }
+            None if n.is_multiple_of(2) => {
+                return Err(format!(
+                    "a cyclic game needs an odd number of moves, not {n}; give a payoff matrix"
+                ))
+            }
+            None => (0..n)
+                .map(|me| {
+                    (0..n)
+                        .map(|elf| match (me + n - elf) % n {
+                            0 => Outcome::Draw,
+                            d if d % 2 == 1 => Outcome::Win,
+                            _ => Outcome::Loss,
+                        })
+                        .collect()
+                })
+                .collect(),
+        };
+        Ok(Game { rules, payoff })
+    }
+
+    /// The game described by day 2's params, the puzzle's Rock Paper Scissors without any.
+    pub fn from_params(params: &params::Params) -> error::Result<Game> {
+        let rules: Rules = params.get(Day02::DAY)?;
+        Game::new(rules).map_err(|reason| Error::invalid_params(Day02::DAY, reason))
+    }
+
+    pub fn outcome(&self, me: usize, elf: usize) -> Outcome {
+        self.payoff[me][elf]
+    }
+
+    /// My score for playing `me` against `elf`.
+    pub fn score(&self, me: usize, elf: usize) -> u32 {
+        self.rules.moves[me].score + self.rules.outcomes[self.outcome(me, elf) as usize].score
+    }
+
+    /// The move to play against `elf` for `outcome`, the best scoring if there are several, or
+    /// `None` if no move has that outcome.
+    pub fn respond(&self, elf: usize, outcome: Outcome) -> Option<usize> {
+        (0..self.rules.moves.len())
+            .filter(|&me| self.outcome(me, elf) == outcome)
+            .max_by_key(|&me| (self.rules.moves[me].score, Reverse(me)))
+    }
+
+    fn parse_elf(&self, letter: &str) -> Option<usize> {
+        self.rules.moves.iter().position(|m| m.elf == letter)
+    }
+
+    fn parse_me(&self, letter: &str) -> Option<usize> {
+        self.rules.moves.iter().position(|m| m.me == letter)
+    }
+
+    fn parse_outcome(&self, letter: &str) -> Option<Outcome> {
+        let outcome = self
+            .rules
+            .outcomes
+            .iter()
+            .position(|o| o.letter == letter)?;
+        Some(Outcome::ALL[outcome])
+    }
+
+    /// The rounds of `guide`. Every letter in the second column has to be one of my moves or
+    /// an outcome.
+    pub fn parse_guide(&self, guide: &str) -> error::Result<Vec<Round>> {
+        numbered_lines(guide)
+            .map(|(line_no, line)| {
+                let (elf, letter) = self.parse_round(line_no, line)?;
+                let (me, outcome) = (self.parse_me(letter), self.parse_outcome(letter));
+                if me.is_none() && outcome.is_none() {
+                    return Err(Error::parse_token(
+                        Day02::DAY,
+                        line_no,
+                        line,
+                        letter,
+                        "invalid move",
+                    ));
+                }
+                Ok(Round {
+                    elf,
+                    me,
+                    outcome,
+                    letter: letter.to_string(),
+                    line: line_no,
+                    column: column_of(line, letter),
+                })
+            })
+            .collect()
+    }
+
+    /// My total score if the second column is my move.
+    pub fn play(&self, rounds: &[Round]) -> error::Result<u32> {
+        rounds
+            .iter()
+            .map(|round| {
+                let me = round.me.ok_or_else(|| round.invalid("invalid move"))?;
+                Ok(self.score(me, round.elf))
+            })
+            .sum()
+    }
+
+    /// My total score if the second column is how the round has to end.
+    pub fn follow(&self, rounds: &[Round]) -> error::Result<u32> {
+        rounds
+            .iter()
+            .map(|round| {
+                let outcome = round.outcome.ok_or_else(|| round.invalid("invalid move"))?;
+                let me = self
+                    .respond(round.elf, outcome)
+                    .ok_or_else(|| round.invalid("no move ends that way"))?;
+                Ok(self.score(me, round.elf))
+            })
+            .sum()
+    }
+
+    /// What `rounds`, as the elf's move and how many times it was played, score when their
+    /// letter means `meaning` of `column`. `None` if one of them can't end that way.
+    fn gain(
+        &self,
+        rounds: impl Iterator<Item = (usize, u64)>,
+        column: Column,
+        meaning: usize,
+    ) -> error::Result<Option<u64>> {
+        let mut total = 0u64;
+        for (elf, count) in rounds {
+            let me = match column {
+                Column::Moves => meaning,
+                Column::Outcomes => match self.respond(elf, Outcome::ALL[meaning]) {
+                    Some(me) => me,
+                    None => return Ok(None),
+                },
+            };
+            total = count
+                .checked_mul(self.score(me, elf).into())
+                .and_then(|score| total.checked_add(score))
+                .ok_or_else(overflow)?;
+        }
+        Ok(Some(total))
+    }
+
+    /// Split a round into the elf's move and the second column's letter.
+    fn parse_round<'a>(&self, line_no: usize, line: &'a str) -> error::Result<(usize, &'a str)> {
+        let mut columns = line.split_whitespace();
+        let (Some(elf), Some(second), None) = (columns.next(), columns.next(), columns.next())
+        else {
+            return Err(Error::parse(
+                Day02::DAY,
+                line_no,
+                1,
+                line,
+                "expected two columns",
+            ));
+        };
+        let elf = self
+            .parse_elf(elf)
+            .ok_or_else(|| Error::parse_token(Day02::DAY, line_no, line, elf, "invalid move"))?;
+        Ok((elf, second))
+    }
+}
+
+/// What the second column of a guide is taken to mean.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Column {
+    Moves,
+    Outcomes,
+}
+
+/// One reading of the second column: each letter in it as a move or an outcome, and the score
+/// that gives.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Interpretation {
+    pub column: Column,
+    /// Letter to move or outcome name.
+    pub mapping: BTreeMap<String, String>,
+    pub score: u64,
+}
+
+/// What an [`Interpretation`] has to satisfy, read from `--target`, `--min`, `--max` and `--fix`.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Constraints {
+    /// The exact total score.
+    pub target: Option<u64>,
+    pub min: Option<u64>,
+    pub max: Option<u64>,
+    /// Letters whose meaning is known, as a move or outcome name.
+    pub fixed: BTreeMap<String, String>,
+}
+
+impl Constraints {
+    /// Fails if a fixed letter means something the game doesn't have.
+    pub fn validate(&self, game: &Game) -> error::Result<()> {
+        let known = |name: &String| {
+            game.rules.moves.iter().any(|m| &m.name == name)
+                || Outcome::ALL.iter().any(|o| o.name() == name)
+        };
+        match self.fixed.iter().find(|(_, name)| !known(name)) {
+            Some((letter, name)) => Err(Error::invalid_params(
+                Day02::DAY,
+                format!("`{letter}` can't mean `{name}`, it is neither a move nor an outcome"),
+            )),
+            None => Ok(()),
+        }
+    }
+
+    /// The lowest and highest total score allowed.
+    fn bounds(&self) -> (u64, u64) {
+        let low = self.target.into_iter().chain(self.min).max();
+        let high = self.target.into_iter().chain(self.max).min();
+        (low.unwrap_or(0), high.unwrap_or(u64::MAX))
+    }
+}
+
+/// Every way to read the second column of `guide` that meets `constraints`: each distinct letter
+/// as a different move, or as a different outcome. Readings where some round can't end the way
+/// its letter says are left out. The elf's column keeps the meaning the rules give it.
+pub fn interpretations(
+    game: &Game,
+    guide: &str,
+    constraints: &Constraints,
+) -> error::Result<Vec<Interpretation>> {
+    constraints.validate(game)?;
+    // rounds with the same letters score the same, so count them instead of replaying each
+    let mut rounds = BTreeMap::new();
+    for (line_no, line) in numbered_lines(guide) {
+        let (elf, letter) = game.parse_round(line_no, line)?;
+        *rounds.entry((letter, elf)).or_insert(0u64) += 1;
+    }
+    let letters = rounds
+        .keys()
+        .map(|&(letter, _)| letter)
+        .dedup()
+        .collect_vec();
+
+    let names = [
+        (
+            Column::Moves,
+            game.rules.moves.iter().map(|m| &m.name[..]).collect_vec(),
+        ),
+        (Column::Outcomes, Outcome::ALL.map(Outcome::name).to_vec()),
+    ];
+    let mut found = vec![];
+    for (column, names) in names {
+        // a letter fixed to a meaning from the other column rules this one out
+        let Some(allowed) = letters
+            .iter()
+            .map(|&letter| match constraints.fixed.get(letter) {
+                Some(name) => names.iter().position(|n| n == name).map(|i| vec![i]),
+                None => Some((0..names.len()).collect()),
+            })
+            .collect::<Option<Vec<_>>>()
+        else {
+            continue;
+        };
+        let gains = letters
+            .iter()
+            .map(|&letter| {
+                let rounds = rounds
+                    .range((letter, 0)..=(letter, usize::MAX))
+                    .map(|(&(_, elf), &count)| (elf, count));
+                (0..names.len())
+                    .map(|meaning| game.gain(rounds.clone(), column, meaning))
+                    .collect::<error::Result<Vec<_>>>()
+            })
+            .collect::<error::Result<Vec<_>>>()?;
+        let search = Search::new(gains, allowed, constraints.bounds());
+        for (picked, score) in search.run()? {
+            let mapping = letters
+                .iter()
+                .zip(picked)
+                .map(|(&letter, index)| (letter.to_string(), names[index].to_string()))
+                .collect();
+            found.push(Interpretation {
+                column,
+                mapping,
+                score,
+            });
+        }
+    }
+    Ok(found)
+}
+
+fn overflow() -> Error {
+    Error::no_solution(Day02::DAY, "the total score doesn't fit in 64 bits")
+}
+
+/// A depth first search giving each letter a meaning no earlier letter has, in order, that drops
+/// a branch as soon as its score can't end up within the bounds.
+struct Search {
+    /// `gains[letter][meaning]` is what the letter's rounds score with that meaning, or `None`
+    /// if one of them can't end the way it says.
+    gains: Vec<Vec<Option<u64>>>,
+    /// The meanings each letter can take.
+    allowed: Vec<Vec<usize>>,
+    /// The most the letters from each one on could still add.
+    best_rest: Vec<u64>,
+    bounds: (u64, u64),
+}
+
+impl Search {
+    fn new(gains: Vec<Vec<Option<u64>>>, allowed: Vec<Vec<usize>>, bounds: (u64, u64)) -> Search {
+        let mut best_rest = vec![0u64; gains.len() + 1];
+        for letter in (0..gains.len()).rev() {
+            let best = allowed[letter]
+                .iter()
+                .filter_map(|&meaning| gains[letter][meaning])
+                .max()
+                .unwrap_or(0);
+            best_rest[letter] = best_rest[letter + 1].saturating_add(best);
+        }
+        Search {
+            gains,
+            allowed,
+            best_rest,
+            bounds,
+        }
+    }
+
+    /// Every complete assignment within the bounds, with its score.
+    fn run(&self) -> error::Result<Vec<(Vec<usize>, u64)>> {
+        let mut found = vec![];
+        self.extend(&mut vec![], 0, &mut found)?;
+        Ok(found)
+    }
+
+    fn extend(
+        &self,
+        picked: &mut Vec<usize>,
+        score: u64,
+        found: &mut Vec<(Vec<usize>, u64)>,
+    ) -> error::Result<()> {
+        let (low, high) = self.bounds;
+        let letter = picked.len();
+        // scores only grow, so past the highest or short of the lowest there is nothing to find
+        if score > high || score.saturating_add(self.best_rest[letter]) < low {
+            return Ok(());
+        }
+        if letter == self.gains.len() {
+            found.push((picked.clone(), score));
+            return Ok(());
+        }
+        for &meaning in &self.allowed[letter] {
+            let Some(gain) = self.gains[letter][meaning] else {
+                continue;
+            };
+            if picked.contains(&meaning) {
+                continue;
+            }
+            picked.push(meaning);
+            self.extend(picked, score.checked_add(gain).ok_or_else(overflow)?, found)?;
+            picked.pop();
+        }
+        Ok(())
+    }
+}
+
+#[derive(Serialize)]
+pub struct Input {
+    pub rounds: Vec<Round>,
+    pub game: Game,
+}
+
+#[aoc_generator(day2)]
+fn generator(input: &str) -> error::Result<Input> {
+    let game = Game::new(Rules::default()).expect("the default rules are valid");
+    Ok(Input {
+        rounds: game.parse_guide(input)?,
+        game,
+    })
+}
+
+#[aoc(day2, part1)]
+fn part_1(input: &Input) -> error::Result<u32> {
+    input.game.play(&input.rounds)
+}
+
+#[aoc(day2, part2)]
+fn part_2(input: &Input) -> error::Result<u32> {
+    input.game.follow(&input.rounds)
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+    type Input = Input;
+
+    fn parse(input: &str) -> error::Result<Input> {
+        generator(input)
+    }
+
+    fn parse_with(input: &str, params: &params::Params) -> error::Result<Input> {
+        let game = Game::from_params(params)?;
+        Ok(Input {
+            rounds: game.parse_guide(input)?,
+            game,
+        })
+    }
+
+    fn part1(input: &Input) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Input) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+}
+
+/// Every [`Interpretation`] of `guide` meeting `constraints`, with the game set up from `params`
+/// and `guide` normalized the way the registry normalizes puzzle input.
+pub fn decipher(
+    guide: &str,
+    params: &params::Params,
+    constraints: &params::Params,
+) -> error::Result<Vec<Interpretation>> {
+    let game = Game::from_params(params)?;
+    let constraints = constraints.get(Day02::DAY)?;
+    interpretations(&game, &normalize(guide), &constraints)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part_2() {
+        let input = fs::read_to_string("test_input/2022/day02.txt").expect("error reading file");
+        assert_eq!(part_2(&generator(&input).unwrap()), Ok(12));
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = fs::read_to_string("test_input/2022/day02.txt").expect("error reading file");
+        assert_eq!(part_1(&generator(&input).unwrap()), Ok(15));
+    }
+
+    #[test]
+    fn test_invalid_round() {
+        assert_eq!(
+            generator("A Y\nB Q\n").err(),
+            Some(Error::parse(2, 2, 3, "Q", "invalid move"))
+        );
+        assert_eq!(
+            generator("A Y\nB\n").err(),
+            Some(Error::parse(2, 2, 1, "B", "expected two columns"))
+        );
+        assert_eq!(
+            generator("D Y\n").err(),
+            Some(Error::parse(2, 1, 1, "D", "invalid move"))
+        );
+    }
+
+    #[test]
+    fn test_interpretations() {
+        let input = fs::read_to_string("test_input/2022/day02.txt").expect("error reading file");
+        let game = Game::new(Rules::default()).unwrap();
+        let found = interpretations(&game, &input, &Constraints::default()).unwrap();
+        assert_eq!(found.len(), 12);
+        let score = |column, meanings: [&str; 3]| {
+            let mapping: BTreeMap<_, _> = ["X", "Y", "Z"]
+                .into_iter()
+                .zip(meanings)
+                .map(|(letter, name)| (letter.to_string(), name.to_string()))
+                .collect();
+            found
+                .iter()
+                .find(|i| i.column == column && i.mapping == mapping)
+                .map(|i| i.score)
+        };
+        assert_eq!(
+            score(Column::Moves, ["rock", "paper", "scissors"]),
+            Some(15)
+        );
+        assert_eq!(
+            score(Column::Moves, ["scissors", "paper", "rock"]),
+            Some(24)
+        );
+        assert_eq!(score(Column::Outcomes, ["loss", "draw", "win"]), Some(12));
+
+        let mut constraints = Constraints {
+            target: Some(15),
+            ..Constraints::default()
+        };
+        assert_eq!(
+            interpretations(&game, &input, &constraints).unwrap().len(),
+            8
+        );
+        constraints
+            .fixed
+            .insert("X".to_string(), "rock".to_string());
+        let matching = interpretations(&game, &input, &constraints).unwrap();
+        assert_eq!(matching.len(), 1);
+        assert_eq!(matching[0].mapping["Z"], "scissors");
+
+        // pruning keeps exactly the readings within the bounds
+        let bounded = Constraints {
+            min: Some(13),
+            max: Some(20),
+            ..Constraints::default()
+        };
+        assert_eq!(
+            interpretations(&game, &input, &bounded).unwrap(),
+            found
+                .iter()
+                .filter(|i| (13..=20).contains(&i.score))
+                .cloned()
+                .collect_vec()
+        );
+
+        constraints
+            .fixed
+            .insert("Y".to_string(), "lizard".to_string());
+        assert_eq!(
+            interpretations(&game, &input, &constraints),
+            Err(Error::invalid_params(
+                2,
+                "`Y` can't mean `lizard`, it is neither a move nor an outcome"
+            ))
+        );
+
+        // a game without draws can't read a letter as one
+        let coin = Game::new(Rules {
+            moves: Rules::default().moves[..2].to_vec(),
+            payoff: Some(vec![
+                vec![Outcome::Win, Outcome::Loss],
+                vec![Outcome::Loss, Outcome::Win],
+            ]),
+            ..Rules::default()
+        })
+        .unwrap();
+        let found = interpretations(&coin, "A X\nB Y\n", &Constraints::default()).unwrap();
+        assert_eq!(
+            found.iter().filter(|i| i.column == Column::Moves).count(),
+            2
+        );
+        assert!(found
+            .iter()
+            .filter(|i| i.column == Column::Outcomes)
+            .all(|i| !i.mapping.values().any(|name| name == "draw")));
+        assert_eq!(
+            interpretations(&game, "A X\nD Y\n", &Constraints::default()),
+            Err(Error::parse(2, 2, 1, "D", "invalid move"))
+        );
+    }
+
+    #[test]
+    fn test_interpretations_fixed() {
+        // 21 moves for 10 letters would be 21!/11! readings, but fixing 9 letters leaves 12
+        let moves = (0..21)
+            .map(|i| Move {
+                name: format!("m{i}"),
+                elf: format!("E{i}"),
+                me: format!("M{i}"),
+                score: i + 1,
+            })
+            .collect();
+        let game = Game::new(Rules {
+            moves,
+            ..Rules::default()
+        })
+        .unwrap();
+        let guide = (0..10).map(|i| format!("E{i} L{i}\n")).collect::<String>();
+        let constraints = Constraints {
+            fixed: (0..9).map(|i| (format!("L{i}"), format!("m{i}"))).collect(),
+            ..Constraints::default()
+        };
+        let found = interpretations(&game, &guide, &constraints).unwrap();
+        assert_eq!(found.len(), 12);
+        assert!(found.iter().all(|i| i.column == Column::Moves));
+        assert_eq!(found[0].mapping["L9"], "m9");
+    }
+
+    #[test]
+    fn test_rpsls() {
+        let params = params::Params::load("test_input/2022/day02.rpsls.json".as_ref()).unwrap();
+        let input = Day02::parse_with("A Y\nC Z\nE V\n", &params).unwrap();
+        assert_eq!(part_1(&input), Ok(22));
+        let input = Day02::parse_with("A Y\nC Z\nE X\n", &params).unwrap();
+        // spock and rock both beat scissors, and spock scores more
+        assert_eq!(part_2(&input), Ok(18));
+        assert_eq!(
+            part_2(&Day02::parse_with("E V\n", &params).unwrap()),
+            Err(Error::parse(2, 1, 3, "V", "invalid move"))
+        );
+    }
+
+    #[test]
+    fn test_payoff_matrix() {
+        let mut params = params::Params::default();
+        params.set(
+            "moves",
+            serde_json::json!([
+                { "name": "heads", "elf": "A", "me": "X", "score": 1 },
+                { "name": "tails", "elf": "B", "me": "Y", "score": 2 },
+            ]),
+        );
+        params.set(
+            "payoff",
+            serde_json::json!([["win", "loss"], ["loss", "win"]]),
+        );
+        let input = Day02::parse_with("A X\nB X\n", &params).unwrap();
+        assert_eq!(part_1(&input), Ok(8));
+        assert_eq!(
+            part_2(&Day02::parse_with("A Y\n", &params).unwrap()),
+            Err(Error::parse(2, 1, 3, "Y", "no move ends that way"))
+        );
+
+        params.set("payoff", serde_json::json!([["win", "loss"]]));
+        assert_eq!(
+            Day02::parse_with("A X\n", &params).err(),
+            Some(Error::invalid_params(2, "the payoff matrix must be 2 by 2"))
+        );
+        params.set("payoff", serde_json::Value::Null);
+        assert_eq!(
+            Day02::parse_with("A X\n", &params).err(),
+            Some(Error::invalid_params(
+                2,
+                "a cyclic game needs an odd number of moves, not 2; give a payoff matrix"
+            ))
+        );
+    }
+}
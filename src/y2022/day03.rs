@@ -0,0 +1,265 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use serde::Serialize;
+
+use crate::{
+    error::{self, Error},
+    solution::{Alternate, Answer, Solution},
+    util::parse::numbered_lines,
+};
+
+/// A rucksack's items, one letter each, along with the line it was listed on.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Rucksack {
+    pub line: usize,
+    pub items: String,
+}
+
+impl Rucksack {
+    /// The items in each compartment, half of them in each.
+    pub fn compartments(&self) -> (&[u8], &[u8]) {
+        self.items.as_bytes().split_at(self.items.len() / 2)
+    }
+}
+
+#[aoc_generator(day3)]
+fn generator(input: &str) -> error::Result<Vec<Rucksack>> {
+    parse_rucksacks(input)
+}
+
+/// Every rucksack in the input. Each one has an even number of items, all of them letters.
+pub fn parse_rucksacks(input: &str) -> error::Result<Vec<Rucksack>> {
+    numbered_lines(input)
+        .map(|(line_no, line)| {
+            let line = line.trim();
+            if let Some((i, item)) = line.char_indices().find(|(_, c)| !c.is_ascii_alphabetic()) {
+                return Err(Error::parse(
+                    Day03::DAY,
+                    line_no,
+                    line[..i].chars().count() + 1,
+                    item.to_string(),
+                    "invalid item type",
+                ));
+            }
+            if line.len() % 2 != 0 {
+                return Err(Error::parse(
+                    Day03::DAY,
+                    line_no,
+                    1,
+                    line,
+                    "rucksack has an odd number of items",
+                ));
+            }
+            Ok(Rucksack {
+                line: line_no,
+                items: line.to_string(),
+            })
+        })
+        .collect()
+}
+
+#[aoc(day3, part1)]
+fn part_1(input: &[Rucksack]) -> error::Result<u32> {
+    input.iter().map(solve_rucksack).sum()
+}
+
+fn solve_rucksack(rucksack: &Rucksack) -> error::Result<u32> {
+    let (first, second) = rucksack.compartments();
+
+    for c in first {
+        let found = second.iter().position(|sc| sc == c);
+        if found.is_some() {
+            if second.iter().any(|sc| sc != c && first.contains(sc)) {
+                return Err(several_common_items(rucksack));
+            }
+            return Ok(priority(*c));
+        }
+    }
+    Err(no_common_item(rucksack))
+}
+
+#[aoc(day3, part1, HashSet)]
+fn part_1_hash_set(input: &[Rucksack]) -> error::Result<u32> {
+    input
+        .iter()
+        .map(|rucksack| {
+            let (first, second) = rucksack.compartments();
+
+            let mut set = HashSet::new();
+            for c in first {
+                set.insert(c);
+            }
+            let mut common = second.iter().filter(|c| set.contains(c)).unique();
+            if let Some(c) = common.next() {
+                if common.next().is_some() {
+                    return Err(several_common_items(rucksack));
+                }
+                return Ok(priority(*c));
+            }
+
+            Err(no_common_item(rucksack))
+        })
+        .sum()
+}
+
+fn no_common_item(rucksack: &Rucksack) -> Error {
+    Error::parse(
+        Day03::DAY,
+        rucksack.line,
+        1,
+        &rucksack.items,
+        "no item type in common",
+    )
+}
+
+fn several_common_items(rucksack: &Rucksack) -> Error {
+    Error::parse(
+        Day03::DAY,
+        rucksack.line,
+        1,
+        &rucksack.items,
+        "more than one item type in common",
+    )
+}
+
+fn priority(item: u8) -> u32 {
+    priority_from_byte(item) as u32
+}
+
+fn priority_from_byte(input: u8) -> u8 {
+    if input <= 90 {
+        input - 38
+    } else {
+        input - 96
+    }
+}
+
+#[aoc(day3, part2)]
+fn part_2(input: &[Rucksack]) -> error::Result<u32> {
+    input
+        .chunks(3)
+        .map(|chunk| {
+            let [first, second, third] = chunk else {
+                return Err(Error::parse(
+                    Day03::DAY,
+                    chunk[0].line,
+                    1,
+                    &chunk[0].items,
+                    "group has fewer than three rucksacks",
+                ));
+            };
+
+            for c in first.items.bytes() {
+                let found_second = second.items.bytes().position(|sc| sc == c);
+                if found_second.is_some() {
+                    let found_third = third.items.bytes().position(|sc| sc == c);
+                    if found_third.is_some() {
+                        return Ok(priority(c));
+                    }
+                }
+            }
+
+            Err(Error::parse(
+                Day03::DAY,
+                first.line,
+                1,
+                &first.items,
+                "no badge shared by the group",
+            ))
+        })
+        .sum()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+    type Input = Vec<Rucksack>;
+
+    fn parse(input: &str) -> error::Result<Vec<Rucksack>> {
+        generator(input)
+    }
+
+    fn part1(input: &Vec<Rucksack>) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Vec<Rucksack>) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn alternates() -> Vec<Alternate<Vec<Rucksack>>> {
+        vec![Alternate {
+            part: 1,
+            name: "HashSet",
+            run: |input| part_1_hash_set(input).map(Answer::from),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn test_part_1() {
+        let input = fs::read_to_string("test_input/2022/day03.txt").expect("error reading file");
+        assert_eq!(part_1(&generator(&input).unwrap()), Ok(157));
+    }
+
+    #[test]
+    fn test_part_1_hash_set() {
+        let input = fs::read_to_string("test_input/2022/day03.txt").expect("error reading file");
+        assert_eq!(part_1_hash_set(&generator(&input).unwrap()), Ok(157));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = fs::read_to_string("test_input/2022/day03.txt").expect("error reading file");
+        assert_eq!(part_2(&generator(&input).unwrap()), Ok(70));
+    }
+
+    #[test]
+    fn test_invalid_rucksack() {
+        assert_eq!(
+            generator("vJrwpWtwJgWrhcsFMMfFFhFp\nabc\n"),
+            Err(Error::parse(
+                3,
+                2,
+                1,
+                "abc",
+                "rucksack has an odd number of items"
+            ))
+        );
+        assert_eq!(
+            generator("ab1b\n"),
+            Err(Error::parse(3, 1, 3, "1", "invalid item type"))
+        );
+        assert_eq!(
+            part_1_hash_set(&generator("abcd\n").unwrap()),
+            Err(Error::parse(3, 1, 1, "abcd", "no item type in common"))
+        );
+        let several = Err(Error::parse(
+            3,
+            1,
+            1,
+            "abBAba",
+            "more than one item type in common",
+        ));
+        let input = generator("abBAba\n").unwrap();
+        assert_eq!(part_1(&input), several);
+        assert_eq!(part_1_hash_set(&input), several);
+        assert_eq!(
+            part_2(&generator("abcd\nefgh\n").unwrap()),
+            Err(Error::parse(
+                3,
+                1,
+                1,
+                "abcd",
+                "group has fewer than three rucksacks"
+            ))
+        );
+    }
+}
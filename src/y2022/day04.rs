@@ -0,0 +1,135 @@
+use crate::{
+    error::{self, Error},
+    solution::{Answer, Solution},
+    util::{intervals, parse::numbered_lines},
+};
+
+/// A section range, first and last section id included.
+pub type Range = (u32, u32);
+
+#[aoc_generator(day4)]
+fn generator(input: &str) -> error::Result<Vec<(Range, Range)>> {
+    parse_pairs(input)
+}
+
+/// The pair of section ranges on each line, like `2-4,6-8`.
+pub fn parse_pairs(input: &str) -> error::Result<Vec<(Range, Range)>> {
+    numbered_lines(input)
+        .map(|(line_no, line)| {
+            let line = line.trim();
+            let sections = line
+                .split(&[',', '-'])
+                .map(|num| {
+                    num.parse::<u32>().map_err(|_| {
+                        Error::parse_token(Day04::DAY, line_no, line, num, "invalid section id")
+                    })
+                })
+                .collect::<error::Result<Vec<_>>>()?;
+            let [a, b, c, d] = sections[..] else {
+                return Err(Error::parse(
+                    Day04::DAY,
+                    line_no,
+                    1,
+                    line,
+                    "expected two section ranges like `2-4,6-8`",
+                ));
+            };
+            if a > b || c > d {
+                return Err(Error::parse(
+                    Day04::DAY,
+                    line_no,
+                    1,
+                    line,
+                    "section range ends before it starts",
+                ));
+            }
+            Ok(((a, b), (c, d)))
+        })
+        .collect()
+}
+
+#[aoc(day4, part1)]
+fn part_1(input: &[(Range, Range)]) -> error::Result<usize> {
+    Ok(input
+        .iter()
+        .filter(|&&(first, second)| {
+            intervals::contains(first, second) || intervals::contains(second, first)
+        })
+        .count())
+}
+
+#[aoc(day4, part2)]
+fn part_2(input: &[(Range, Range)]) -> error::Result<usize> {
+    Ok(input
+        .iter()
+        .filter(|&&(first, second)| intervals::overlaps(first, second))
+        .count())
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+    type Input = Vec<(Range, Range)>;
+
+    fn parse(input: &str) -> error::Result<Vec<(Range, Range)>> {
+        generator(input)
+    }
+
+    fn part1(input: &Vec<(Range, Range)>) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Vec<(Range, Range)>) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input =
+            fs::read_to_string("test_input/2022/day04.txt").expect("Error reading test input file");
+        assert_eq!(part_1(&generator(&input).unwrap()), Ok(2));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input =
+            fs::read_to_string("test_input/2022/day04.txt").expect("Error reading test input file");
+        assert_eq!(part_2(&generator(&input).unwrap()), Ok(4));
+    }
+
+    #[test]
+    fn test_invalid_pair() {
+        assert_eq!(
+            generator("2-4,6-8\n2-x,4-5\n"),
+            Err(Error::parse(4, 2, 3, "x", "invalid section id"))
+        );
+        assert_eq!(
+            generator("2-4\n"),
+            Err(Error::parse(
+                4,
+                1,
+                1,
+                "2-4",
+                "expected two section ranges like `2-4,6-8`"
+            ))
+        );
+        assert_eq!(
+            generator("4-2,6-8\n"),
+            Err(Error::parse(
+                4,
+                1,
+                1,
+                "4-2,6-8",
+                "section range ends before it starts"
+            ))
+        );
+    }
+}
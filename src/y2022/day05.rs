@@ -0,0 +1,248 @@
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{self, Error},
+    params,
+    solution::{Answer, Solution},
+    util::parse::split_records,
+};
+
+/// Move `count` crates from stack `from` to stack `to`, counting stacks from 1.
+#[derive(Debug, Serialize)]
+pub struct Command {
+    pub count: usize,
+    pub from: usize,
+    pub to: usize,
+}
+
+pub type Stacks = Vec<Vec<char>>;
+
+/// Stacks are listed bottom crate first.
+#[derive(Serialize)]
+pub struct Input {
+    pub stacks: Stacks,
+    pub commands: Vec<Command>,
+}
+
+/// Size of the crate drawing: the sample has 3 stacks at most 3 high.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub stacks: usize,
+    pub height: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            stacks: 9,
+            height: 8,
+        }
+    }
+}
+
+fn generator(input: &str, num_stacks: usize, stack_height: usize) -> error::Result<Input> {
+    let mut stacks: Vec<Vec<char>> = vec![];
+    for _ in 0..num_stacks {
+        stacks.push(vec![]);
+    }
+
+    let records = split_records(input);
+    let [drawing, moves] = records[..] else {
+        return Err(Error::parse(
+            Day05::DAY,
+            records.get(2).map_or(1, |record| record.line),
+            1,
+            format!("{} sections", records.len()),
+            "expected a crate drawing and a list of moves separated by a blank line",
+        ));
+    };
+
+    drawing
+        .text
+        .lines()
+        .take(stack_height)
+        .for_each(|stack_line| {
+            let stack_line = stack_line.chars().collect_vec();
+            for (i, stack) in stacks.iter_mut().enumerate() {
+                // editors may strip trailing spaces, so a short line means empty stacks
+                let c = stack_line.get(1 + (i * 4)).copied().unwrap_or(' ');
+                if c != ' ' {
+                    stack.insert(0, c);
+                }
+            }
+        });
+
+    let commands = moves
+        .lines()
+        .map(|(line_no, command_line)| parse_command(line_no, command_line, num_stacks))
+        .collect::<error::Result<Vec<_>>>()?;
+
+    Ok(Input { stacks, commands })
+}
+
+fn parse_command(line_no: usize, line: &str, num_stacks: usize) -> error::Result<Command> {
+    let words = line.split_whitespace().collect_vec();
+    let [_, count, _, from, _, to] = words[..] else {
+        return Err(Error::parse(
+            Day05::DAY,
+            line_no,
+            1,
+            line,
+            "expected `move <count> from <stack> to <stack>`",
+        ));
+    };
+    let number = |token: &str| {
+        token
+            .parse::<usize>()
+            .map_err(|_| Error::parse_token(Day05::DAY, line_no, line, token, "invalid number"))
+    };
+    let stack = |token: &str| {
+        number(token).and_then(|stack| {
+            if (1..=num_stacks).contains(&stack) {
+                Ok(stack)
+            } else {
+                Err(Error::parse_token(
+                    Day05::DAY,
+                    line_no,
+                    line,
+                    token,
+                    format!("stack out of range 1..={num_stacks}"),
+                ))
+            }
+        })
+    };
+    Ok(Command {
+        count: number(count)?,
+        from: stack(from)?,
+        to: stack(to)?,
+    })
+}
+
+#[aoc_generator(day5)]
+fn input_generator(input: &str) -> error::Result<Input> {
+    let params = Params::default();
+    generator(input, params.stacks, params.height)
+}
+
+#[aoc(day5, part1)]
+fn part_1(input: &Input) -> error::Result<String> {
+    let mut stacks = input.stacks.clone();
+    for command in input.commands.iter() {
+        if stacks[command.from - 1].len() < command.count {
+            return Err(not_enough_crates(command));
+        }
+        for _ in 0..command.count {
+            let c = stacks[command.from - 1].pop().unwrap();
+            stacks[command.to - 1].push(c);
+        }
+    }
+
+    read_answer(&stacks)
+}
+
+#[aoc(day5, part2)]
+fn part_2(input: &Input) -> error::Result<String> {
+    let mut stacks = input.stacks.clone();
+    for command in input.commands.iter() {
+        let stack_size = stacks[command.from - 1].len();
+        if stack_size < command.count {
+            return Err(not_enough_crates(command));
+        }
+        let mut drained = stacks[command.from - 1]
+            .drain((stack_size - command.count)..)
+            .collect_vec();
+        stacks[command.to - 1].append(&mut drained);
+    }
+
+    read_answer(&stacks)
+}
+
+fn not_enough_crates(command: &Command) -> Error {
+    Error::no_solution(
+        Day05::DAY,
+        format!(
+            "cannot move {} crates from stack {}",
+            command.count, command.from
+        ),
+    )
+}
+
+fn read_answer(stacks: &Stacks) -> error::Result<String> {
+    let mut answer = String::new();
+    for (i, stack) in stacks.iter().enumerate() {
+        let top = stack.last().ok_or_else(|| {
+            Error::no_solution(Day05::DAY, format!("stack {} ends up empty", i + 1))
+        })?;
+        answer.push(*top);
+    }
+    Ok(answer)
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+    type Input = Input;
+
+    fn parse(input: &str) -> error::Result<Input> {
+        input_generator(input)
+    }
+
+    fn parse_with(input: &str, params: &params::Params) -> error::Result<Input> {
+        let params: Params = params.get(Self::DAY)?;
+        generator(input, params.stacks, params.height)
+    }
+
+    fn part1(input: &Input) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Input) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input =
+            fs::read_to_string("test_input/2022/day05.txt").expect("Error reading test input file");
+        let input = generator(&input, 3, 3).unwrap();
+        assert_eq!(part_1(&input), Ok("CMZ".to_string()));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input =
+            fs::read_to_string("test_input/2022/day05.txt").expect("Error reading test input file");
+        let input = generator(&input, 3, 3).unwrap();
+        assert_eq!(part_2(&input), Ok("MCD".to_string()));
+    }
+
+    #[test]
+    fn test_params() {
+        let input =
+            fs::read_to_string("test_input/2022/day05.txt").expect("Error reading test input file");
+        let params = params::Params::load("test_input/2022/day05.params.json".as_ref()).unwrap();
+        let input = Day05::parse_with(&input, &params).unwrap();
+        assert_eq!(part_1(&input), Ok("CMZ".to_string()));
+    }
+
+    #[test]
+    fn test_invalid_command() {
+        let input =
+            fs::read_to_string("test_input/2022/day05.txt").expect("Error reading test input file");
+        let input = input.replace("move 2 from 2 to 1", "move 2 from 4 to 1");
+        assert_eq!(
+            generator(&input, 3, 3).err(),
+            Some(Error::parse(5, 8, 13, "4", "stack out of range 1..=3"))
+        );
+    }
+}
@@ -0,0 +1,139 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use crate::{
+    error::{self, Error},
+    solution::{Answer, Solution},
+    util::parse::numbered_lines,
+};
+
+#[aoc_generator(day6)]
+fn generator(input: &str) -> error::Result<Vec<u8>> {
+    parse_datastream(input)
+}
+
+/// The datastream's characters. It's all on one line, though an empty input is an empty stream.
+pub fn parse_datastream(input: &str) -> error::Result<Vec<u8>> {
+    match numbered_lines(input).collect_vec()[..] {
+        [] => Ok(vec![]),
+        [(_, line)] => Ok(line.trim().bytes().collect()),
+        [_, (line_no, line), ..] => Err(Error::parse(
+            Day06::DAY,
+            line_no,
+            1,
+            line,
+            "expected the datastream on a single line",
+        )),
+    }
+}
+
+#[aoc(day6, part1)]
+fn part_1(chars: &[u8]) -> error::Result<usize> {
+    for i in 3..chars.len() {
+        if chars[i - 3] != chars[i - 2]
+            && chars[i - 3] != chars[i - 1]
+            && chars[i - 3] != chars[i]
+            && chars[i - 2] != chars[i - 1]
+            && chars[i - 2] != chars[i]
+            && chars[i - 1] != chars[i]
+        {
+            return Ok(i + 1);
+        }
+    }
+
+    Err(Error::no_solution(Day06::DAY, "no start-of-packet marker"))
+}
+
+#[aoc(day6, part2)]
+fn part_2(chars: &[u8]) -> error::Result<usize> {
+    let mut set = HashSet::new();
+    for i in 14..chars.len() {
+        set.clear();
+        for j in 0..14 {
+            if !set.insert(chars[i - j]) {
+                break;
+            }
+        }
+        if set.len() == 14 {
+            return Ok(i + 1);
+        }
+    }
+    Err(Error::no_solution(Day06::DAY, "no start-of-message marker"))
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+    type Input = Vec<u8>;
+
+    fn parse(input: &str) -> error::Result<Vec<u8>> {
+        generator(input)
+    }
+
+    fn part1(input: &Vec<u8>) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Vec<u8>) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE_1: &str = "mjqjpqmgbljsphdztnvjfqwrcgsmlb";
+    const SAMPLE_2: &str = "bvwbjplbgvbhsrlpgdmjqwftvncz";
+    const SAMPLE_3: &str = "nppdvjthqldpwncqszvftbrmjlhg";
+    const SAMPLE_4: &str = "nznrnfrfntjfmvfwmzdfjlvtqnbhcprsg";
+    const SAMPLE_5: &str = "zcfzfwzzqfrljwzlrfnpqdbhtmscgvjw";
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(part_1(SAMPLE_1.as_bytes()), Ok(7));
+        assert_eq!(part_1(SAMPLE_2.as_bytes()), Ok(5));
+        assert_eq!(part_1(SAMPLE_3.as_bytes()), Ok(6));
+        assert_eq!(part_1(SAMPLE_4.as_bytes()), Ok(10));
+        assert_eq!(part_1(SAMPLE_5.as_bytes()), Ok(11));
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(part_2(SAMPLE_1.as_bytes()), Ok(19));
+        assert_eq!(part_2(SAMPLE_2.as_bytes()), Ok(23));
+        assert_eq!(part_2(SAMPLE_3.as_bytes()), Ok(23));
+        assert_eq!(part_2(SAMPLE_4.as_bytes()), Ok(29));
+        assert_eq!(part_2(SAMPLE_5.as_bytes()), Ok(26));
+    }
+
+    #[test]
+    fn test_generator() {
+        assert_eq!(generator("abc\n"), Ok(b"abc".to_vec()));
+        assert_eq!(generator(""), Ok(vec![]));
+        assert_eq!(
+            generator("abc\n\ndef\n"),
+            Err(Error::parse(
+                6,
+                3,
+                1,
+                "def",
+                "expected the datastream on a single line"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_no_marker() {
+        assert_eq!(
+            part_1(b"abcabc"),
+            Err(Error::no_solution(6, "no start-of-packet marker"))
+        );
+        assert_eq!(
+            part_2(&SAMPLE_1.as_bytes()[..10]),
+            Err(Error::no_solution(6, "no start-of-message marker"))
+        );
+    }
+}
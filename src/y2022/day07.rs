@@ -0,0 +1,242 @@
+use std::collections::{BTreeMap, HashMap};
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize, Serializer};
+
+use crate::{
+    error::{self, Error},
+    params,
+    solution::{Answer, Solution},
+    util::parse::numbered_lines,
+};
+
+/// The total size of every directory, subdirectories included, keyed by its path.
+pub type FileSystem = HashMap<Vec<String>, u32>;
+
+#[derive(Debug, PartialEq, Eq, Serialize)]
+pub struct Input {
+    #[serde(serialize_with = "serialize_file_system")]
+    pub file_system: FileSystem,
+    pub params: Params,
+}
+
+/// Directory sizes keyed by path, like `{"/": 48381165, "/a": 94853, "/a/e": 584}`.
+fn serialize_file_system<S: Serializer>(
+    file_system: &FileSystem,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    file_system
+        .iter()
+        .map(|(path, size)| (format!("/{}", path.iter().skip(1).join("/")), size))
+        .collect::<BTreeMap<_, _>>()
+        .serialize(serializer)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Directories counted by part 1 are at most this big.
+    pub at_most: u32,
+    pub disk_size: u32,
+    /// Free space the update needs.
+    pub needed: u32,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            at_most: 100000,
+            disk_size: 70000000,
+            needed: 30000000,
+        }
+    }
+}
+
+#[aoc_generator(day7)]
+fn generator(input: &str) -> error::Result<Input> {
+    Ok(Input {
+        file_system: parse_file_system(input)?,
+        params: Params::default(),
+    })
+}
+
+fn parse_file_system(input: &str) -> error::Result<FileSystem> {
+    let mut file_system = HashMap::new();
+    file_system.insert(vec!["/".to_string()], 0);
+    let mut pwd: Vec<String> = vec![];
+
+    for (line_no, raw_line) in numbered_lines(input) {
+        let line = raw_line.split_whitespace().collect_vec();
+        let invalid = |token: &str, reason: &str| {
+            Error::parse_token(Day07::DAY, line_no, raw_line, token, reason)
+        };
+        match line[..] {
+            ["$", "cd", "/"] => {
+                pwd = vec!["/".to_string()];
+            }
+            ["$", "cd", ".."] => {
+                pwd.pop();
+            }
+            ["$", "cd", dir] => {
+                // $ cd <dir>
+                pwd.push(dir.to_string());
+                let dir = file_system.get_mut(&pwd);
+                let dir = match dir {
+                    Some(d) => d.to_owned(),
+                    None => 0,
+                };
+                file_system.insert(pwd.clone(), dir);
+            }
+            ["$", "ls"] => {
+                // do nothing?
+            }
+            ["$", command, ..] => {
+                return Err(invalid(command, "command not recognized"));
+            }
+            ["dir", _] => {
+                // do nothing?
+            }
+            [size, _] => {
+                let size: u32 = size
+                    .parse()
+                    .map_err(|_| invalid(size, "invalid file size"))?;
+
+                // bump all dir sizes
+                for i in 1..(pwd.len() + 1) {
+                    let path = &pwd[0..i];
+                    *file_system.get_mut(path).unwrap() += size;
+                }
+            }
+            _ => {
+                return Err(invalid(raw_line.trim(), "expected a command or listing"));
+            }
+        };
+    }
+
+    Ok(file_system)
+}
+
+#[aoc(day7, part1)]
+#[allow(clippy::iter_kv_map)]
+fn part_1(input: &Input) -> error::Result<u32> {
+    Ok(input
+        .file_system
+        .iter()
+        .map(|(_, dir)| {
+            if *dir <= input.params.at_most {
+                return *dir;
+            }
+            0
+        })
+        .sum())
+}
+
+#[aoc(day7, part2)]
+#[allow(clippy::iter_kv_map)]
+fn part_2(input: &Input) -> error::Result<u32> {
+    let root = vec!["/".to_string()];
+    let Params {
+        disk_size, needed, ..
+    } = input.params;
+    let max_used = disk_size
+        .checked_sub(needed)
+        .ok_or_else(|| Error::no_solution(Day07::DAY, "the update is bigger than the disk"))?;
+    let to_free_size = input
+        .file_system
+        .get(&root)
+        .unwrap()
+        .checked_sub(max_used)
+        .ok_or_else(|| Error::no_solution(Day07::DAY, "enough space is already free"))?;
+    input
+        .file_system
+        .iter()
+        .filter_map(|(_, dir)| {
+            if *dir >= to_free_size {
+                return Some(*dir);
+            }
+            None
+        })
+        .sorted()
+        .next()
+        .ok_or_else(|| Error::no_solution(Day07::DAY, "no directory is big enough"))
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+    type Input = Input;
+
+    fn parse(input: &str) -> error::Result<Input> {
+        generator(input)
+    }
+
+    fn parse_with(input: &str, params: &params::Params) -> error::Result<Input> {
+        Ok(Input {
+            file_system: parse_file_system(input)?,
+            params: params.get(Self::DAY)?,
+        })
+    }
+
+    fn part1(input: &Input) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Input) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input =
+            fs::read_to_string("test_input/2022/day07.txt").expect("Error reading test input file");
+        let input = generator(&input).unwrap();
+        assert_eq!(part_1(&input), Ok(95437));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input =
+            fs::read_to_string("test_input/2022/day07.txt").expect("Error reading test input file");
+        let input = generator(&input).unwrap();
+        assert_eq!(part_2(&input), Ok(24933642));
+    }
+
+    #[test]
+    fn test_params() {
+        let input =
+            fs::read_to_string("test_input/2022/day07.txt").expect("Error reading test input file");
+        let mut params = params::Params::default();
+        params.set("at_most", 1000);
+        params.set("needed", 21700000);
+        let input = Day07::parse_with(&input, &params).unwrap();
+        assert_eq!(part_1(&input), Ok(584));
+        assert_eq!(part_2(&input), Ok(94853));
+
+        params.set("needed", 80000000);
+        let input = Day07::parse_with("$ cd /\n", &params).unwrap();
+        assert_eq!(
+            part_2(&input),
+            Err(Error::no_solution(7, "the update is bigger than the disk"))
+        );
+    }
+
+    #[test]
+    fn test_invalid_line() {
+        assert_eq!(
+            generator("$ cd /\n$ ls\n12x b.txt\n"),
+            Err(Error::parse(7, 3, 1, "12x", "invalid file size"))
+        );
+        assert_eq!(
+            generator("$ cd /\n$ rm -rf a\n"),
+            Err(Error::parse(7, 2, 3, "rm", "command not recognized"))
+        );
+    }
+}
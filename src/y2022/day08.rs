@@ -0,0 +1,166 @@
+use crate::{
+    error::{self, Error},
+    solution::{Answer, Solution},
+    util::grid::{Dir, Grid, Pos},
+    viz::{Cell, Frame, Render, Rgb},
+};
+
+type Input = Grid<u8>;
+
+#[aoc_generator(day08)]
+fn generator(input: &str) -> error::Result<Input> {
+    let grid = Grid::from_chars(Day08::DAY, input, "invalid tree height", |_, c| {
+        c.to_digit(10).map(|d| d as u8)
+    })?;
+    if grid.width() == 0 {
+        return Err(Error::no_solution(Day08::DAY, "no trees in input"));
+    }
+
+    Ok(grid)
+}
+
+#[aoc(day08, part1)]
+fn part_1(grid: &Input) -> error::Result<usize> {
+    let visible = grid
+        .iter()
+        .filter(|&(pos, &val)| is_visible(grid, pos, val))
+        .count();
+
+    Ok(visible)
+}
+
+fn is_visible(grid: &Input, pos: Pos, val: u8) -> bool {
+    // trees on the edge have empty rays, so they are always visible
+    Dir::ALL
+        .into_iter()
+        .any(|dir| grid.ray(pos, dir).all(|(_, &tree)| tree < val))
+}
+
+#[aoc(day08, part2)]
+fn part_2(grid: &Input) -> error::Result<usize> {
+    let high_score = grid
+        .iter()
+        .map(|(pos, &val)| {
+            Dir::ALL
+                .into_iter()
+                .map(|dir| {
+                    let mut score = 0;
+                    for (_, &tree) in grid.ray(pos, dir) {
+                        score += 1;
+                        if tree >= val {
+                            break;
+                        }
+                    }
+                    score
+                })
+                .product()
+        })
+        .max()
+        .unwrap_or(0);
+
+    Ok(high_score)
+}
+
+/// The forest with trees visible from outside in green and hidden ones in grey, taller trees
+/// brighter.
+struct Forest<'a>(&'a Input);
+
+impl Render for Forest<'_> {
+    fn frames(&self) -> Vec<Frame> {
+        let grid = self.0;
+        let mut frame = Grid::new(grid.width(), grid.height(), Cell::default());
+        for (pos, &val) in grid.iter() {
+            let (dark, bright) = if is_visible(grid, pos, val) {
+                (Rgb(0, 60, 0), Rgb(120, 255, 80))
+            } else {
+                (Rgb(30, 30, 30), Rgb(140, 140, 140))
+            };
+            let color = dark.mix(bright, val as f32 / 9.0);
+            frame.set(pos, Cell::new((b'0' + val) as char, color));
+        }
+        vec![frame]
+    }
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+    type Input = Input;
+
+    fn parse(input: &str) -> error::Result<Input> {
+        generator(input)
+    }
+
+    fn part1(input: &Input) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Input) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn render(input: &Input) -> Option<error::Result<Vec<Frame>>> {
+        Some(Ok(Forest(input).frames()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input =
+            fs::read_to_string("test_input/2022/day08.txt").expect("Error reading test input file");
+        let input = generator(&input).unwrap();
+        assert_eq!(part_1(&input), Ok(21));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input =
+            fs::read_to_string("test_input/2022/day08.txt").expect("Error reading test input file");
+        let input = generator(&input).unwrap();
+        assert_eq!(part_2(&input), Ok(8));
+    }
+
+    #[test]
+    fn test_render() {
+        let text =
+            fs::read_to_string("test_input/2022/day08.txt").expect("Error reading test input file");
+        let input = generator(&text).unwrap();
+        let frames = Forest(&input).frames();
+        assert_eq!(frames.len(), 1);
+        assert_eq!(frames[0].to_string(), text.trim());
+        let hidden = frames[0]
+            .iter()
+            .filter(|(_, cell)| cell.color.0 == cell.color.1)
+            .count();
+        assert_eq!(hidden, 25 - 21);
+    }
+
+    #[test]
+    fn test_invalid_grid() {
+        assert_eq!(
+            generator("303\n2x5\n653\n"),
+            Err(Error::parse(8, 2, 2, "x", "invalid tree height"))
+        );
+        assert_eq!(
+            generator("303\n25\n653\n"),
+            Err(Error::parse(8, 2, 1, "25", "expected 3 cells in every row"))
+        );
+    }
+
+    #[test]
+    fn test_rectangular_grid() {
+        let input = generator("3037\n2552\n").unwrap();
+        assert_eq!(part_1(&input), Ok(8));
+
+        let input = generator("30373\n25512\n65332\n").unwrap();
+        assert_eq!(part_1(&input), Ok(14));
+        assert_eq!(part_2(&input), Ok(2));
+    }
+}
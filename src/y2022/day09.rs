@@ -0,0 +1,309 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+
+use tracing::trace;
+
+use crate::{
+    error::{self, Error},
+    solution::{Answer, Solution},
+    util::{
+        grid::{Dir, Grid, Pos},
+        parse::numbered_lines,
+    },
+    viz::{self, Cell, Frame, Render, Rgb},
+};
+
+/// Where `tail` moves to after `head` has moved: it stays put while the two touch, and otherwise
+/// takes one step towards `head`, diagonally if they're in neither the same row nor column.
+pub fn resolve_tail(head: Pos, mut tail: Pos) -> Pos {
+    let hor = head.x.abs_diff(tail.x) > 1;
+    let ver = head.y.abs_diff(tail.y) > 1;
+    if hor && head.y != tail.y || ver && head.x != tail.x {
+        if head.x > tail.x && head.y > tail.y {
+            tail.x += 1;
+            tail.y += 1;
+        } else if head.x > tail.x && head.y < tail.y {
+            tail.x += 1;
+            tail.y -= 1;
+        } else if head.x < tail.x && head.y > tail.y {
+            tail.x -= 1;
+            tail.y += 1;
+        } else {
+            tail.x -= 1;
+            tail.y -= 1;
+        }
+        trace!(%head, %tail, "tail follows diagonally");
+    } else if hor {
+        if head.x > tail.x {
+            tail.x += 1;
+        } else {
+            tail.x -= 1;
+        }
+        trace!(%head, %tail, "tail follows horizontally");
+    } else if ver {
+        if head.y > tail.y {
+            tail.y += 1;
+        } else {
+            tail.y -= 1;
+        }
+        trace!(%head, %tail, "tail follows vertically");
+    };
+
+    tail
+}
+
+/// A rope of knots, each one following the knot in front of it.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Rope {
+    knots: Vec<Pos>,
+}
+
+impl Rope {
+    /// A rope of `knots` knots, all at the origin. A rope has at least one knot, its head.
+    pub fn new(knots: usize) -> Rope {
+        Rope {
+            knots: vec![Pos::default(); knots.max(1)],
+        }
+    }
+
+    /// The knots, head first.
+    pub fn knots(&self) -> &[Pos] {
+        &self.knots
+    }
+
+    pub fn head(&self) -> Pos {
+        self.knots[0]
+    }
+
+    pub fn tail(&self) -> Pos {
+        self.knots[self.knots.len() - 1]
+    }
+
+    /// Move the head one step in `direction` and pull the rest of the rope after it.
+    pub fn step(&mut self, direction: Dir) {
+        self.knots[0] = self.knots[0].step(direction);
+        for i in 1..self.knots.len() {
+            self.knots[i] = resolve_tail(self.knots[i - 1], self.knots[i]);
+        }
+    }
+}
+
+/// The number of positions the tail of a rope with `knots` knots visits, the start included.
+pub fn tail_positions(motions: &[(Dir, u32)], knots: usize) -> usize {
+    let mut rope = Rope::new(knots);
+    let mut visited = HashSet::from([rope.tail()]);
+    for &(direction, steps) in motions {
+        for _ in 0..steps {
+            rope.step(direction);
+            visited.insert(rope.tail());
+        }
+        trace!(?direction, steps, knots = ?rope.knots(), "moved");
+    }
+    visited.len()
+}
+
+/// The motions in the puzzle input, as a direction and a number of steps.
+pub fn parse_motions(input: &str) -> error::Result<Vec<(Dir, u32)>> {
+    numbered_lines(input)
+        .map(|(line_no, motion)| parse_motion(line_no, motion))
+        .collect()
+}
+
+fn parse_motion(line_no: usize, line: &str) -> error::Result<(Dir, u32)> {
+    let mut motion = line.split_whitespace();
+    let (Some(direction), Some(steps), None) = (motion.next(), motion.next(), motion.next()) else {
+        return Err(Error::parse(
+            Day09::DAY,
+            line_no,
+            1,
+            line,
+            "expected a direction and a step count",
+        ));
+    };
+    let invalid = |token, reason| Error::parse_token(Day09::DAY, line_no, line, token, reason);
+    let direction = match direction.chars().collect::<Vec<_>>()[..] {
+        [c] => Dir::from_char(c),
+        _ => None,
+    }
+    .ok_or_else(|| invalid(direction, "invalid direction"))?;
+    let steps = steps
+        .parse::<u32>()
+        .map_err(|_| invalid(steps, "invalid step count"))?;
+    Ok((direction, steps))
+}
+
+#[aoc_generator(day09)]
+fn generator(input: &str) -> error::Result<Vec<(Dir, u32)>> {
+    parse_motions(input)
+}
+
+#[aoc(day09, part1)]
+fn part_1(motions: &[(Dir, u32)]) -> error::Result<usize> {
+    Ok(tail_positions(motions, 2))
+}
+
+#[aoc(day09, part2)]
+fn part_2(motions: &[(Dir, u32)]) -> error::Result<usize> {
+    Ok(tail_positions(motions, 10))
+}
+
+/// The 10 knot rope of part 2 following the motions, leaving a trail where the tail has been.
+struct Trail<'a> {
+    motions: &'a [(Dir, u32)],
+}
+
+impl Trail<'_> {
+    /// The knots after every step, starting with all of them at the origin.
+    fn states(&self) -> Vec<Vec<Pos>> {
+        let mut rope = Rope::new(10);
+        let mut states = vec![rope.knots().to_vec()];
+        for &(direction, steps) in self.motions {
+            for _ in 0..steps {
+                rope.step(direction);
+                states.push(rope.knots().to_vec());
+            }
+        }
+        states
+    }
+}
+
+impl Render for Trail<'_> {
+    fn frames(&self) -> Vec<Frame> {
+        let states = self.states();
+        let knots = states.iter().flatten();
+        // there is always the starting state, so the knots are never empty
+        let (min_x, max_x) = knots
+            .clone()
+            .map(|knot| knot.x)
+            .minmax()
+            .into_option()
+            .unwrap();
+        let (min_y, max_y) = knots.map(|knot| knot.y).minmax().into_option().unwrap();
+        let origin = Pos::new(min_x - 1, min_y - 1);
+        let (width, height) = ((max_x - min_x + 3) as usize, (max_y - min_y + 3) as usize);
+        let background = Grid::with_origin(origin, width, height, Cell::new('.', Rgb(20, 20, 40)));
+
+        let shown: HashSet<usize> = viz::sample((0..states.len()).collect(), 200)
+            .into_iter()
+            .collect();
+        let mut visited = HashSet::new();
+        let mut frames = vec![];
+        for (i, snake) in states.iter().enumerate() {
+            visited.insert(snake[9]);
+            if !shown.contains(&i) {
+                continue;
+            }
+            let mut frame = background.clone();
+            for &pos in &visited {
+                frame.set(pos, Cell::new('#', Rgb(70, 90, 160)));
+            }
+            // the head goes on top of the others
+            for (knot, &pos) in snake.iter().enumerate().rev() {
+                let cell = match knot {
+                    0 => Cell::new('H', Rgb(255, 60, 40)),
+                    _ => Cell::new(
+                        char::from_digit(knot as u32, 10).unwrap(),
+                        Rgb(255, 220, 60).mix(Rgb(255, 120, 40), knot as f32 / 9.0),
+                    ),
+                };
+                frame.set(pos, cell);
+            }
+            frames.push(frame);
+        }
+        frames
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+    type Input = Vec<(Dir, u32)>;
+
+    fn parse(input: &str) -> error::Result<Vec<(Dir, u32)>> {
+        generator(input)
+    }
+
+    fn part1(input: &Vec<(Dir, u32)>) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Vec<(Dir, u32)>) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn render(input: &Vec<(Dir, u32)>) -> Option<error::Result<Vec<Frame>>> {
+        Some(Ok(Trail { motions: input }.frames()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input =
+            fs::read_to_string("test_input/2022/day09.txt").expect("Error reading test input file");
+        assert_eq!(part_1(&generator(&input).unwrap()), Ok(13));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input =
+            fs::read_to_string("test_input/2022/day09.txt").expect("Error reading test input file");
+        assert_eq!(part_2(&generator(&input).unwrap()), Ok(1));
+    }
+
+    #[test]
+    fn test_part_2_2() {
+        let input = fs::read_to_string("test_input/2022/day09-2.txt")
+            .expect("Error reading test input file");
+        assert_eq!(part_2(&generator(&input).unwrap()), Ok(36));
+    }
+
+    #[test]
+    fn test_rope() {
+        let mut rope = Rope::new(3);
+        for _ in 0..3 {
+            rope.step(Dir::Right);
+        }
+        assert_eq!(
+            rope.knots(),
+            [Pos::new(3, 0), Pos::new(2, 0), Pos::new(1, 0)]
+        );
+        assert_eq!(Rope::new(0).knots().len(), 1);
+
+        let motions = parse_motions("R 4\nU 4\n").unwrap();
+        assert_eq!(tail_positions(&motions, 1), 9);
+        assert_eq!(tail_positions(&motions, 2), 7);
+    }
+
+    #[test]
+    fn test_render() {
+        let input = fs::read_to_string("test_input/2022/day09-2.txt")
+            .expect("Error reading test input file");
+        let motions = generator(&input).unwrap();
+        let frames = Trail { motions: &motions }.frames();
+        // one frame per step, plus the start
+        assert_eq!(frames.len(), 97);
+        assert_eq!(frames[0].get(Pos::default()).map(|cell| cell.c), Some('H'));
+        let trail = frames[96].iter().filter(|(_, cell)| cell.c != '.').count();
+        assert_eq!(trail, 36 + 9);
+    }
+
+    #[test]
+    fn test_invalid_motion() {
+        assert_eq!(
+            generator("R 4\nX 2\n"),
+            Err(Error::parse(9, 2, 1, "X", "invalid direction"))
+        );
+        assert_eq!(
+            generator("R 4\nU -2\n"),
+            Err(Error::parse(9, 2, 3, "-2", "invalid step count"))
+        );
+    }
+}
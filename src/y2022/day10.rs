@@ -0,0 +1,258 @@
+use itertools::Itertools;
+use tracing::debug;
+
+use crate::{
+    error::{self, Error},
+    solution::{Alternate, Answer, Solution},
+    util::{
+        grid::{Grid, Pos},
+        parse::numbered_lines,
+    },
+    viz::{Cell, Frame, Render, Rgb},
+};
+
+#[aoc_generator(day10)]
+fn generator(input: &str) -> error::Result<Vec<i32>> {
+    let mut x = 1;
+    let mut cycles = vec![];
+    for (line_no, line) in numbered_lines(input) {
+        let ins = line.split_whitespace().collect_vec();
+        match ins[..] {
+            ["noop"] => {
+                cycles.push(x);
+            }
+            ["addx", val] => {
+                let pre_x = x;
+                let val: i32 = val.parse().map_err(|_| {
+                    Error::parse_token(Day10::DAY, line_no, line, val, "invalid addx value")
+                })?;
+                x += val;
+                cycles.extend([pre_x, pre_x]);
+            }
+            _ => {
+                return Err(Error::parse(
+                    Day10::DAY,
+                    line_no,
+                    1,
+                    line.trim(),
+                    "expected `noop` or `addx <value>`",
+                ))
+            }
+        }
+    }
+    Ok(cycles)
+}
+
+#[aoc(day10, part1)]
+fn part_1(input: &[i32]) -> error::Result<i32> {
+    Ok(input
+        .iter()
+        .enumerate()
+        .filter_map(|(i, x)| match i {
+            19 | 59 | 99 | 139 | 179 | 219 => {
+                debug!(cycle = i + 1, x, "signal strength");
+                Some((1 + i as i32) * x)
+            }
+            _ => None,
+        })
+        .sum())
+}
+
+fn map_sprite(i: usize, x: i32) -> char {
+    let cycle = i as i32;
+    if x == cycle || x == cycle - 1 || x == cycle + 1 {
+        '#'
+    } else {
+        '.'
+    }
+}
+
+/// The screen is 6 rows of 40 pixels, one drawn per cycle.
+fn check_screen(input: &[i32]) -> error::Result<()> {
+    if input.len() < 240 {
+        return Err(Error::no_solution(
+            Day10::DAY,
+            format!(
+                "the program stops after {} cycles, before the screen is drawn",
+                input.len()
+            ),
+        ));
+    }
+    Ok(())
+}
+
+#[aoc(day10, part2)]
+fn part_2(input: &[i32]) -> error::Result<String> {
+    check_screen(input)?;
+    Ok((0..6)
+        .map(|i| {
+            input
+                .iter()
+                .skip(i * 40)
+                .take(40)
+                .enumerate()
+                .map(|(i, x)| map_sprite(i, *x))
+                .collect::<String>()
+        })
+        .join("\n"))
+}
+
+#[aoc(day10, part2, chunks)]
+fn part_2_chunks(input: &[i32]) -> error::Result<String> {
+    check_screen(input)?;
+    Ok(input
+        .chunks(40)
+        .take(6)
+        .map(|chunk| {
+            chunk
+                .iter()
+                .enumerate()
+                .map(|(i, x)| map_sprite(i, *x))
+                .collect::<String>()
+        })
+        .join("\n"))
+}
+
+/// The CRT drawing one pixel per cycle.
+struct Crt<'a>(&'a [i32]);
+
+impl Render for Crt<'_> {
+    fn frames(&self) -> Vec<Frame> {
+        let mut screen = Grid::new(40, 6, Cell::new(' ', Rgb(10, 20, 10)));
+        let mut frames = vec![];
+        for (cycle, &x) in self.0.iter().take(240).enumerate() {
+            let pos = Pos::new((cycle % 40) as i32, (cycle / 40) as i32);
+            let cell = match map_sprite(cycle % 40, x) {
+                '#' => Cell::new('#', Rgb(120, 255, 120)),
+                c => Cell::new(c, Rgb(20, 60, 20)),
+            };
+            screen.set(pos, cell);
+            frames.push(screen.clone());
+        }
+        frames
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+    type Input = Vec<i32>;
+
+    fn parse(input: &str) -> error::Result<Vec<i32>> {
+        generator(input)
+    }
+
+    fn part1(input: &Vec<i32>) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Vec<i32>) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn render(input: &Vec<i32>) -> Option<error::Result<Vec<Frame>>> {
+        Some(Ok(Crt(input).frames()))
+    }
+
+    fn alternates() -> Vec<Alternate<Vec<i32>>> {
+        vec![Alternate {
+            part: 2,
+            name: "chunks",
+            run: |input| part_2_chunks(input).map(Answer::from),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_generator() {
+        let input = "noop
+        addx 3
+        addx -5";
+
+        assert_eq!(generator(input), Ok(vec![1, 1, 1, 4, 4]));
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input =
+            fs::read_to_string("test_input/2022/day10.txt").expect("Error reading test input file");
+        let input = generator(&input).unwrap();
+        assert_eq!(part_1(&input), Ok(13140));
+    }
+
+    const PART_2_EXPECTED: &str = "##..##..##..##..##..##..##..##..##..##..
+###...###...###...###...###...###...###.
+####....####....####....####....####....
+#####.....#####.....#####.....#####.....
+######......######......######......####
+#######.......#######.......#######.....";
+
+    #[test]
+    fn test_part_2() {
+        let input =
+            fs::read_to_string("test_input/2022/day10.txt").expect("Error reading test input file");
+        let input = generator(&input).unwrap();
+        assert_eq!(part_2(&input).unwrap(), PART_2_EXPECTED);
+    }
+
+    #[test]
+    fn test_part_2_chunks() {
+        let input =
+            fs::read_to_string("test_input/2022/day10.txt").expect("Error reading test input file");
+        let input = generator(&input).unwrap();
+        assert_eq!(part_2_chunks(&input).unwrap(), PART_2_EXPECTED);
+    }
+
+    #[test]
+    fn test_part_2_screen_size() {
+        let input =
+            fs::read_to_string("test_input/2022/day10.txt").expect("Error reading test input file");
+        let input = generator(&(input + "\nnoop\nnoop\n")).unwrap();
+        assert_eq!(part_2(&input).unwrap(), PART_2_EXPECTED);
+        assert_eq!(part_2_chunks(&input).unwrap(), PART_2_EXPECTED);
+
+        let short = Err(Error::no_solution(
+            10,
+            "the program stops after 5 cycles, before the screen is drawn",
+        ));
+        let input = generator("noop\naddx 3\naddx -5\n").unwrap();
+        assert_eq!(part_2(&input), short);
+        assert_eq!(part_2_chunks(&input), short);
+    }
+
+    #[test]
+    fn test_render() {
+        let input =
+            fs::read_to_string("test_input/2022/day10.txt").expect("Error reading test input file");
+        let input = generator(&input).unwrap();
+        let frames = Crt(&input).frames();
+        assert_eq!(frames.len(), 240);
+        assert_eq!(frames[239].to_string(), PART_2_EXPECTED);
+        assert!(frames[0].to_string().starts_with("#  "));
+    }
+
+    #[test]
+    fn test_invalid_instruction() {
+        assert_eq!(
+            generator("noop\naddx three\n"),
+            Err(Error::parse(10, 2, 6, "three", "invalid addx value"))
+        );
+        assert_eq!(
+            generator("noop\nmulx 3\n"),
+            Err(Error::parse(
+                10,
+                2,
+                1,
+                "mulx 3",
+                "expected `noop` or `addx <value>`"
+            ))
+        );
+    }
+}
@@ -0,0 +1,536 @@
+use std::str::FromStr;
+
+use itertools::Itertools;
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    error::{self, Error},
+    params,
+    solution::{Answer, Solution},
+    util::parse::{split_records, Record},
+};
+
+/// How a monkey changes the worry level of an item it inspects.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Operation {
+    Add(u128),
+    Multiply(u128),
+    Square,
+}
+
+impl Operation {
+    pub fn apply(self, item: u128) -> u128 {
+        match self {
+            Operation::Add(value) => item + value,
+            Operation::Multiply(value) => item * value,
+            Operation::Square => item * item,
+        }
+    }
+}
+
+/// A monkey holding items, each one a worry level.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct Monkey {
+    items: Vec<u128>,
+    inspected: u128,
+    operation: Operation,
+    test: u128,
+    t: usize,
+    f: usize,
+}
+
+impl Monkey {
+    /// A monkey that throws to monkey `if_true` when an item's worry level is divisible by
+    /// `test`, and to `if_false` otherwise.
+    pub fn new(
+        items: Vec<u128>,
+        operation: Operation,
+        test: u128,
+        if_true: usize,
+        if_false: usize,
+    ) -> Monkey {
+        Monkey {
+            items,
+            inspected: 0,
+            operation,
+            test,
+            t: if_true,
+            f: if_false,
+        }
+    }
+
+    pub fn items(&self) -> &[u128] {
+        &self.items
+    }
+
+    /// How many items the monkey has inspected so far.
+    pub fn inspected(&self) -> u128 {
+        self.inspected
+    }
+
+    fn throw_to(&self, item: u128) -> usize {
+        if item.is_multiple_of(self.test) {
+            self.t
+        } else {
+            self.f
+        }
+    }
+}
+
+/// Parse the monkeys' notes, one record per monkey in order:
+///
+/// ```text
+/// Monkey 0:
+///   Starting items: 79, 98
+///   Operation: new = old * 19
+///   Test: divisible by 23
+///     If true: throw to monkey 2
+///     If false: throw to monkey 3
+/// ```
+pub fn parse_monkeys(input: &str) -> error::Result<Vec<Monkey>> {
+    let records = split_records(input);
+    records
+        .iter()
+        .enumerate()
+        .map(|(index, record)| parse_monkey(index, records.len(), record))
+        .collect()
+}
+
+/// Parse the notes on monkey number `index` of `count`.
+fn parse_monkey(index: usize, count: usize, record: &Record) -> error::Result<Monkey> {
+    let lines = record.lines().collect_vec();
+    let [header, items, operation, test, if_true, if_false] = lines[..] else {
+        return Err(Error::parse(
+            Day11::DAY,
+            record.line,
+            1,
+            format!("{} lines", lines.len()),
+            "expected a monkey's number, items, operation, test and both targets",
+        ));
+    };
+
+    let (line_no, line) = header;
+    if line.trim() != format!("Monkey {index}:") {
+        return Err(Error::parse_token(
+            Day11::DAY,
+            line_no,
+            line,
+            line.trim(),
+            format!("expected \"Monkey {index}:\""),
+        ));
+    }
+
+    let (line_no, line, items) = field(items, "Starting items:")?;
+    let items = items
+        .split(',')
+        .map(|item| number(line_no, line, item.trim()))
+        .collect::<error::Result<Vec<u128>>>()?;
+
+    let (line_no, line, operation) = field(operation, "Operation: new =")?;
+    let operation = match operation.split_whitespace().collect_vec()[..] {
+        ["old", "*", "old"] => Operation::Square,
+        ["old", "*", value] => Operation::Multiply(number(line_no, line, value)?),
+        ["old", "+", value] => Operation::Add(number(line_no, line, value)?),
+        _ => {
+            return Err(Error::parse_token(
+                Day11::DAY,
+                line_no,
+                line,
+                operation,
+                "expected old * old, old * <number> or old + <number>",
+            ))
+        }
+    };
+
+    let (line_no, line, test) = field(test, "Test: divisible by")?;
+    let test = number(line_no, line, test)?;
+    if test == 0 {
+        return Err(Error::parse_token(
+            Day11::DAY,
+            line_no,
+            line,
+            line.trim(),
+            "nothing is divisible by 0",
+        ));
+    }
+
+    let target = |note, label| {
+        let (line_no, line, target) = field(note, label)?;
+        match number(line_no, line, target)? {
+            target if target < count => Ok(target),
+            _ => Err(Error::parse_token(
+                Day11::DAY,
+                line_no,
+                line,
+                target,
+                format!("there are only {count} monkeys"),
+            )),
+        }
+    };
+    let if_true = target(if_true, "If true: throw to monkey")?;
+    let if_false = target(if_false, "If false: throw to monkey")?;
+
+    Ok(Monkey::new(items, operation, test, if_true, if_false))
+}
+
+/// The rest of a note's line after `label`.
+fn field<'a>(
+    (line_no, line): (usize, &'a str),
+    label: &str,
+) -> error::Result<(usize, &'a str, &'a str)> {
+    match line.trim().strip_prefix(label) {
+        Some(rest) => Ok((line_no, line, rest.trim())),
+        None => Err(Error::parse_token(
+            Day11::DAY,
+            line_no,
+            line,
+            line.trim(),
+            format!("expected \"{label}\""),
+        )),
+    }
+}
+
+fn number<T: FromStr>(line_no: usize, line: &str, token: &str) -> error::Result<T> {
+    token
+        .parse()
+        .map_err(|_| Error::parse_token(Day11::DAY, line_no, line, token, "invalid number"))
+}
+
+#[derive(Serialize)]
+pub struct Input {
+    pub monkeys: Vec<Monkey>,
+    pub params: Params,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    pub part1_rounds: usize,
+    pub part2_rounds: usize,
+    /// Worry levels are divided by this after each inspection in part 1.
+    pub divisor: u128,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            part1_rounds: 20,
+            part2_rounds: 10_000,
+            divisor: 3,
+        }
+    }
+}
+
+impl Params {
+    fn validate(self) -> error::Result<Self> {
+        if self.divisor == 0 {
+            return Err(Error::invalid_params(
+                Day11::DAY,
+                "`divisor` must be at least 1",
+            ));
+        }
+        Ok(self)
+    }
+}
+
+#[aoc_generator(day11)]
+fn generator(input: &str) -> error::Result<Input> {
+    Ok(Input {
+        monkeys: parse_monkeys(input)?,
+        params: Params::default(),
+    })
+}
+
+#[aoc(day11, part1)]
+fn part_1(input: &Input) -> error::Result<u128> {
+    solve_part_1(input.monkeys.clone(), &input.params)
+}
+
+fn solve_part_1(monkeys: Vec<Monkey>, params: &Params) -> error::Result<u128> {
+    monkey_business(monkeys, params.part1_rounds, Some(params.divisor))
+}
+
+#[aoc(day11, part2)]
+fn part_2(input: &Input) -> error::Result<u128> {
+    solve_part_2(input.monkeys.clone(), input.params.part2_rounds)
+}
+
+fn solve_part_2(monkeys: Vec<Monkey>, rounds: usize) -> error::Result<u128> {
+    monkey_business(monkeys, rounds, None)
+}
+
+fn find_modulo(monkeys: &[Monkey]) -> error::Result<u128> {
+    if monkeys.is_empty() {
+        return Err(Error::no_solution(Day11::DAY, "there are no monkeys"));
+    }
+    monkeys.iter().try_fold(1u128, |modulo, monkey| {
+        modulo.checked_mul(monkey.test).ok_or_else(|| {
+            Error::no_solution(
+                Day11::DAY,
+                "the product of the monkeys' tests doesn't fit in 128 bits",
+            )
+        })
+    })
+}
+
+/// The product of the two highest inspection counts after `rounds` rounds. With `worry_div`,
+/// worry levels are divided by it after every inspection. Fails if `worry_div` is zero, or if
+/// there are no monkeys or the product of their tests doesn't fit in a `u128`.
+pub fn monkey_business(
+    mut monkeys: Vec<Monkey>,
+    rounds: usize,
+    worry_div: Option<u128>,
+) -> error::Result<u128> {
+    if worry_div == Some(0) {
+        return Err(Error::invalid_params(
+            Day11::DAY,
+            "`divisor` must be at least 1",
+        ));
+    }
+    let modulo = find_modulo(&monkeys)?;
+    for _ in 0..rounds {
+        process_round(&mut monkeys, worry_div, modulo);
+    }
+    Ok(monkeys
+        .iter()
+        .map(|monkey| monkey.inspected)
+        .sorted()
+        .rev()
+        .take(2)
+        .product())
+}
+
+/// Every monkey in turn inspects and throws all of its items. Worry levels are kept below
+/// `modulo`, the product of every monkey's test, which doesn't change where items are thrown.
+pub fn process_round(monkeys: &mut [Monkey], worry_div: Option<u128>, modulo: u128) {
+    for i in 0..monkeys.len() {
+        let items = monkeys[i].items.drain(0..).collect_vec();
+        for item in items {
+            let item = item % modulo;
+            let item = monkeys[i].operation.apply(item);
+            let item = if let Some(divider) = worry_div {
+                item / divider
+            } else {
+                item
+            };
+            let throw_to = monkeys[i].throw_to(item);
+            monkeys[throw_to].items.push(item);
+            monkeys[i].inspected += 1;
+        }
+    }
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+    type Input = Input;
+
+    fn parse(input: &str) -> error::Result<Input> {
+        generator(input)
+    }
+
+    fn parse_with(input: &str, params: &params::Params) -> error::Result<Input> {
+        Ok(Input {
+            monkeys: parse_monkeys(input)?,
+            params: params.get::<Params>(Self::DAY)?.validate()?,
+        })
+    }
+
+    fn part1(input: &Input) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Input) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_monkey() {
+        let monkey = Monkey {
+            items: vec![79, 98],
+            inspected: 0,
+            operation: Operation::Multiply(19),
+            test: 23,
+            t: 2,
+            f: 3,
+        };
+
+        assert_eq!(monkey.operation.apply(79), 1501);
+        assert_eq!(monkey.throw_to(79), 3);
+        assert_eq!(monkey.throw_to(23), 2);
+        assert_eq!(monkey.throw_to(46), 2);
+    }
+
+    fn get_test_monkeys() -> Vec<Monkey> {
+        vec![
+            Monkey {
+                items: vec![79, 98],
+                inspected: 0,
+                operation: Operation::Multiply(19),
+                test: 23,
+                t: 2,
+                f: 3,
+            },
+            Monkey {
+                items: vec![54, 65, 75, 74],
+                inspected: 0,
+                operation: Operation::Add(6),
+                test: 19,
+                t: 2,
+                f: 0,
+            },
+            Monkey {
+                items: vec![79, 60, 97],
+                inspected: 0,
+                operation: Operation::Square,
+                test: 13,
+                t: 1,
+                f: 3,
+            },
+            Monkey {
+                items: vec![74],
+                inspected: 0,
+                operation: Operation::Add(3),
+                test: 17,
+                t: 0,
+                f: 1,
+            },
+        ]
+    }
+
+    #[test]
+    fn test_generator() {
+        let input =
+            fs::read_to_string("test_input/2022/day11.txt").expect("Error reading test input file");
+        assert_eq!(generator(&input).unwrap().monkeys, get_test_monkeys());
+
+        let notes = |operation: &str, if_false: &str| {
+            format!(
+                "Monkey 0:\n  Starting items: 79, 98\n  Operation: new = {operation}\n  \
+                 Test: divisible by 23\n    If true: throw to monkey 0\n    \
+                 If false: throw to monkey {if_false}\n"
+            )
+        };
+        assert_eq!(generator(&notes("old * 19", "0")).unwrap().monkeys.len(), 1);
+        assert_eq!(
+            generator(&notes("old - 19", "0")).err(),
+            Some(Error::parse(
+                11,
+                3,
+                20,
+                "old - 19",
+                "expected old * old, old * <number> or old + <number>"
+            ))
+        );
+        assert_eq!(
+            generator(&notes("old * 19", "1")).err(),
+            Some(Error::parse(11, 6, 31, "1", "there are only 1 monkeys"))
+        );
+        assert_eq!(
+            generator("Monkey 1:\n").err(),
+            Some(Error::parse(
+                11,
+                1,
+                1,
+                "1 lines",
+                "expected a monkey's number, items, operation, test and both targets"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_process_round() {
+        let mut monkeys = get_test_monkeys();
+        let modulo = find_modulo(&monkeys).unwrap();
+        process_round(&mut monkeys, Some(3), modulo);
+
+        assert_eq!(monkeys[0].items, vec![20, 23, 27, 26]);
+        assert_eq!(monkeys[0].inspected, 2);
+
+        assert_eq!(monkeys[1].items, vec![2080, 25, 167, 207, 401, 1046]);
+        assert_eq!(monkeys[1].inspected, 4);
+
+        assert_eq!(monkeys[2].items, vec![]);
+        assert_eq!(monkeys[2].inspected, 3);
+
+        assert_eq!(monkeys[3].items, vec![]);
+        assert_eq!(monkeys[3].inspected, 5);
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = get_test_monkeys();
+        assert_eq!(solve_part_1(input, &Params::default()), Ok(10605));
+    }
+
+    #[test]
+    fn test_part_2_1() {
+        let input = get_test_monkeys();
+        assert_eq!(solve_part_2(input, 1), Ok(24));
+    }
+
+    #[test]
+    fn test_part_2_20() {
+        let input = get_test_monkeys();
+        assert_eq!(solve_part_2(input, 20), Ok(10197));
+    }
+
+    #[test]
+    fn test_part_2_1000() {
+        let input = get_test_monkeys();
+        assert_eq!(solve_part_2(input, 1000), Ok(27019168));
+    }
+
+    #[test]
+    fn test_part_2_10000() {
+        let input = get_test_monkeys();
+        assert_eq!(solve_part_2(input, 10_000), Ok(2713310158));
+    }
+
+    #[test]
+    fn test_find_modulo() {
+        assert_eq!(find_modulo(&get_test_monkeys()), Ok(23 * 19 * 13 * 17));
+        assert_eq!(
+            find_modulo(&[]),
+            Err(Error::no_solution(11, "there are no monkeys"))
+        );
+
+        let mut monkeys = get_test_monkeys();
+        monkeys[0].test = u128::MAX;
+        assert_eq!(
+            find_modulo(&monkeys),
+            Err(Error::no_solution(
+                11,
+                "the product of the monkeys' tests doesn't fit in 128 bits"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_zero_divisor() {
+        let input = fs::read_to_string("test_input/2022/day11.txt").unwrap();
+        let mut params = params::Params::default();
+        params.set("divisor", 0);
+        let err = Err(Error::invalid_params(11, "`divisor` must be at least 1"));
+        assert_eq!(Day11::parse_with(&input, &params).map(|_| ()), err);
+        assert_eq!(
+            solve_part_1(
+                get_test_monkeys(),
+                &Params {
+                    divisor: 0,
+                    ..Params::default()
+                }
+            ),
+            err.map(|()| 0)
+        );
+    }
+}
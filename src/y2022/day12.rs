@@ -0,0 +1,283 @@
+extern crate pathfinding;
+
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use pathfinding::prelude::bfs;
+use rayon::prelude::*;
+
+use serde::Serialize;
+
+use crate::{
+    dump,
+    error::{self, Error},
+    solution::{Alternate, Answer, Solution},
+    util::grid::{Dir, Grid, Pos},
+    viz::{self, Cell, Frame, Render, Rgb},
+};
+
+/// Elevations are the letters' ASCII codes, from `b'a'` to `b'z'`.
+#[derive(Debug, Serialize)]
+pub struct Heightmap {
+    pub grid: Grid<u8>,
+    pub start: Pos,
+    pub end: Pos,
+    /// Every square at elevation `a`.
+    #[serde(serialize_with = "dump::sorted_set")]
+    pub lowest: HashSet<Pos>,
+}
+
+#[aoc_generator(day12)]
+fn generator(input: &str) -> error::Result<Heightmap> {
+    let mut start = None;
+    let mut end = None;
+    let mut lowest = HashSet::new();
+
+    let grid = Grid::from_chars(Day12::DAY, input, "invalid elevation", |pos, c| match c {
+        'S' => {
+            start = Some(pos);
+            Some(b'a')
+        }
+        'E' => {
+            end = Some(pos);
+            Some(b'z')
+        }
+        'a' => {
+            lowest.insert(pos);
+            Some(b'a')
+        }
+        'b'..='z' => Some(c as u8),
+        _ => None,
+    })?;
+
+    let start = start.ok_or_else(|| Error::no_solution(Day12::DAY, "no start position `S`"))?;
+    let end = end.ok_or_else(|| Error::no_solution(Day12::DAY, "no best signal position `E`"))?;
+    lowest.insert(start);
+
+    Ok(Heightmap {
+        grid,
+        start,
+        end,
+        lowest,
+    })
+}
+
+#[aoc(day12, part1)]
+fn part_1(input: &Heightmap) -> error::Result<usize> {
+    let result = shortest_path(input).ok_or_else(no_path)?;
+    Ok(result.len() - 1)
+}
+
+fn shortest_path(input: &Heightmap) -> Option<Vec<Pos>> {
+    bfs(
+        &input.start,
+        |&pos| {
+            Dir::ALL
+                .into_iter()
+                .flat_map(|dir| check_direction(input, pos, dir))
+                .collect_vec()
+        },
+        |&p| p == input.end,
+    )
+}
+
+fn no_path() -> Error {
+    Error::no_solution(Day12::DAY, "no path reaches the best signal")
+}
+
+fn check_direction(input: &Heightmap, pos: Pos, dir: Dir) -> Option<Pos> {
+    let current_height = *input
+        .grid
+        .get(pos)
+        .expect("Tried to access point not on the grid!");
+    let dest = pos.step(dir);
+    if let Some(dest_height) = input.grid.get(dest) {
+        if current_height >= dest_height - 1 {
+            return Some(dest);
+        }
+    }
+    None
+}
+
+#[aoc(day12, part2)]
+fn part_2(input: &Heightmap) -> error::Result<usize> {
+    input
+        .lowest
+        .iter()
+        .filter_map(|start| steps_from(input, start))
+        .sorted()
+        .next()
+        .ok_or_else(no_path)
+}
+
+/// The same as [`part_2`], with the BFS from each lowest square run in parallel.
+#[aoc(day12, part2, rayon)]
+fn part_2_rayon(input: &Heightmap) -> error::Result<usize> {
+    input
+        .lowest
+        .par_iter()
+        .filter_map(|start| steps_from(input, start))
+        .min()
+        .ok_or_else(no_path)
+}
+
+fn steps_from(input: &Heightmap, start: &Pos) -> Option<usize> {
+    let result = bfs(
+        start,
+        |&pos| {
+            Dir::ALL
+                .into_iter()
+                .flat_map(|dir| check_direction(input, pos, dir))
+                .collect_vec()
+        },
+        |&p| p == input.end,
+    );
+    if let Some(res) = result {
+        Some(res.len()).map(|len| len - 1)
+    } else {
+        None
+    }
+}
+
+/// The heightmap from blue valleys to white peaks, with part 1's path climbing from `S` to `E`
+/// marked out like the puzzle's drawing.
+impl Render for Heightmap {
+    fn frames(&self) -> Vec<Frame> {
+        let mut frame = Grid::new(self.grid.width(), self.grid.height(), Cell::default());
+        for (pos, &height) in self.grid.iter() {
+            let t = (height - b'a') as f32 / 25.0;
+            let color = if t < 0.5 {
+                Rgb(30, 60, 140).mix(Rgb(120, 90, 50), t * 2.0)
+            } else {
+                Rgb(120, 90, 50).mix(Rgb::WHITE, t * 2.0 - 1.0)
+            };
+            frame.set(pos, Cell::new(height as char, color));
+        }
+        frame.set(self.start, Cell::new('S', Rgb(255, 60, 40)));
+        frame.set(self.end, Cell::new('E', Rgb(255, 60, 40)));
+
+        let Some(path) = shortest_path(self) else {
+            return vec![frame];
+        };
+        let steps = path
+            .iter()
+            .tuple_windows()
+            .map(|(&from, &to)| {
+                let c = match to - from {
+                    Pos { x: 1, .. } => '>',
+                    Pos { x: -1, .. } => '<',
+                    Pos { y: 1, .. } => 'v',
+                    _ => '^',
+                };
+                (from, Cell::new(c, Rgb(255, 160, 40)))
+            })
+            .collect_vec();
+        let shown: HashSet<usize> = viz::sample((0..steps.len()).collect(), 200)
+            .into_iter()
+            .collect();
+        let mut frames = vec![frame.clone()];
+        for (i, (pos, cell)) in steps.into_iter().enumerate() {
+            frame.set(pos, cell);
+            if shown.contains(&i) {
+                frames.push(frame.clone());
+            }
+        }
+        frames
+    }
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    type Input = Heightmap;
+
+    fn parse(input: &str) -> error::Result<Heightmap> {
+        generator(input)
+    }
+
+    fn part1(input: &Heightmap) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Heightmap) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn render(input: &Heightmap) -> Option<error::Result<Vec<Frame>>> {
+        Some(Ok(input.frames()))
+    }
+
+    fn alternates() -> Vec<Alternate<Heightmap>> {
+        vec![Alternate {
+            part: 2,
+            name: "rayon",
+            run: |input| part_2_rayon(input).map(Answer::from),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "Sabqponm
+abcryxxl
+accszExk
+acctuvwj
+abdefghi
+";
+
+    #[test]
+    fn test_check_direction() {
+        let input = generator(SAMPLE).unwrap();
+
+        let origin = Pos::new(0, 0);
+        assert_eq!(check_direction(&input, origin, Dir::Up), None);
+        assert_eq!(
+            check_direction(&input, origin, Dir::Down),
+            Some(Pos::new(0, 1))
+        );
+        assert_eq!(check_direction(&input, origin, Dir::Left), None);
+        assert_eq!(
+            check_direction(&input, origin, Dir::Right),
+            Some(Pos::new(1, 0))
+        );
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = generator(SAMPLE).unwrap();
+        assert_eq!(part_1(&input), Ok(31));
+    }
+
+    #[test]
+    fn test_render() {
+        let input = generator(SAMPLE).unwrap();
+        let frames = input.frames();
+        assert_eq!(frames.len(), 32);
+        assert_eq!(frames[0].to_string(), SAMPLE.trim());
+        let last = frames[31].to_string();
+        assert_eq!(last.matches(['<', '>', '^', 'v']).count(), 31);
+        assert!(last.contains('E'));
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input = generator(SAMPLE).unwrap();
+        assert_eq!(part_2(&input), Ok(29));
+        assert_eq!(part_2_rayon(&input), Ok(29));
+    }
+
+    #[test]
+    fn test_invalid_heightmap() {
+        assert_eq!(
+            generator("Sab\nab?\nabE\n").err(),
+            Some(Error::parse(12, 2, 3, "?", "invalid elevation"))
+        );
+        assert_eq!(
+            generator("Sab\nabc\n").err(),
+            Some(Error::no_solution(12, "no best signal position `E`"))
+        );
+    }
+}
@@ -0,0 +1,312 @@
+use std::{
+    cmp::Ordering::{self, Equal, Greater, Less},
+    ops::ControlFlow::{self, Break, Continue},
+};
+
+use itertools::{
+    EitherOrBoth::{Both, Left, Right},
+    Itertools,
+};
+use serde::Serialize;
+use serde_json::Value;
+use tracing::trace;
+
+use crate::{
+    error::{self, Error},
+    solution::{Answer, Solution},
+    util::parse::{numbered_lines, split_records},
+};
+
+/// A left and a right packet.
+pub type Pair = (Vec<Packet>, Vec<Packet>);
+
+#[aoc_generator(day13)]
+fn generator(input: &str) -> error::Result<Vec<Pair>> {
+    parse_pairs(input)
+}
+
+/// The pairs of packets in the input, each pair separated from the next by a blank line.
+pub fn parse_pairs(input: &str) -> error::Result<Vec<Pair>> {
+    split_records(input)
+        .iter()
+        .map(|pair| {
+            let mut packets = pair
+                .lines()
+                .map(|(line_no, line)| parse_packet(line_no, line.trim()))
+                .collect::<error::Result<Vec<_>>>()?;
+            if packets.len() != 2 {
+                return Err(Error::parse(
+                    Day13::DAY,
+                    pair.line,
+                    1,
+                    format!("{} packets", packets.len()),
+                    "expected a pair of packets",
+                ));
+            }
+            let right = packets.pop().unwrap();
+            let left = packets.pop().unwrap();
+            Ok((left, right))
+        })
+        .collect()
+}
+
+#[aoc(day13, part1)]
+fn part_1(input: &[Pair]) -> error::Result<usize> {
+    let mut sum = 0;
+    for (i, (left, right)) in input.iter().enumerate() {
+        if in_right_order(left, right) {
+            sum += i + 1;
+        }
+    }
+    Ok(sum)
+}
+
+/// A packet value. A whole packet is the list of values on its line, see [`parse_packets`].
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(untagged)]
+pub enum Packet {
+    Int(u64),
+    List(Vec<Packet>),
+}
+
+fn to_packet(value: &Value) -> Option<Packet> {
+    match value {
+        Value::Number(number) => number.as_u64().map(Packet::Int),
+        Value::Array(list) => list
+            .iter()
+            .map(to_packet)
+            .collect::<Option<_>>()
+            .map(Packet::List),
+        _ => None,
+    }
+}
+
+fn test(left: &Packet, right: &Packet) -> ControlFlow<bool> {
+    trace!(?left, ?right, "compare");
+    match (left, right) {
+        (Packet::Int(left), Packet::Int(right)) => match left.cmp(right) {
+            Less => Break(true),
+            Greater => Break(false),
+            Equal => Continue(()),
+        },
+        (Packet::List(left), Packet::List(right)) => solve_pair_rec(left, right),
+        (Packet::Int(left), Packet::List(right)) => solve_pair_rec(&[Packet::Int(*left)], right),
+        (Packet::List(left), Packet::Int(right)) => solve_pair_rec(left, &[Packet::Int(*right)]),
+    }
+}
+
+/// Whether the pair is in the right order. Identical packets are not.
+pub fn in_right_order(input_left: &[Packet], input_right: &[Packet]) -> bool {
+    match solve_pair_rec(input_left, input_right) {
+        Break(val) => val,
+        Continue(_) => false,
+    }
+}
+
+/// The order of two packets, with `Equal` for packets neither of which comes first, like `[[1]]`
+/// and `[1]`.
+pub fn compare(left: &[Packet], right: &[Packet]) -> Ordering {
+    match solve_pair_rec(left, right) {
+        Break(true) => Less,
+        Break(false) => Greater,
+        Continue(_) => Equal,
+    }
+}
+
+fn solve_pair_rec(input_left: &[Packet], input_right: &[Packet]) -> ControlFlow<bool> {
+    input_left
+        .iter()
+        .zip_longest(input_right.iter())
+        .try_for_each(|pair| match pair {
+            Both(left, right) => test(left, right),
+            // actually right
+            Left(_) => Break(false),
+            // actually left
+            Right(_) => Break(true),
+        })
+}
+
+fn parse_packet(line_no: usize, line: &str) -> error::Result<Vec<Packet>> {
+    let value: Value = serde_json::from_str(line).map_err(|err| {
+        Error::parse(
+            Day13::DAY,
+            line_no,
+            err.column().max(1),
+            line,
+            "invalid packet",
+        )
+    })?;
+    match to_packet(&value) {
+        Some(Packet::List(packet)) => Ok(packet),
+        _ => Err(Error::parse(
+            Day13::DAY,
+            line_no,
+            1,
+            line,
+            "packets must be lists of lists and integers",
+        )),
+    }
+}
+
+/// Every packet in the input, skipping the blank lines between pairs.
+pub fn parse_packets(input: &str) -> error::Result<Vec<Vec<Packet>>> {
+    numbered_lines(input)
+        .map(|(line_no, line)| parse_packet(line_no, line.trim()))
+        .collect()
+}
+
+fn from_str(input: &str) -> Vec<Packet> {
+    parse_packet(0, input).expect("invalid packet literal")
+}
+
+const DIV_2: &str = "[[2]]";
+const DIV_6: &str = "[[6]]";
+
+#[aoc(day13, part2)]
+fn part_2(input: &[Pair]) -> error::Result<u32> {
+    let packets = input
+        .iter()
+        .flat_map(|(left, right)| [left.clone(), right.clone()])
+        .collect();
+    Ok(decoder_key(packets))
+}
+
+/// Sort `packets` along with the divider packets `[[2]]` and `[[6]]`, and multiply the dividers'
+/// positions, counting from 1.
+pub fn decoder_key(mut packets: Vec<Vec<Packet>>) -> u32 {
+    packets.push(from_str(DIV_2));
+    packets.push(from_str(DIV_6));
+
+    let res = packets
+        .into_iter()
+        .sorted_by(|left, right| compare(left, right))
+        .collect_vec();
+
+    let div_2 = from_str(DIV_2);
+    let div_6 = from_str(DIV_6);
+
+    let div_2_pos = res.iter().position(|item| item == &div_2).unwrap() as u32;
+    let div_6_pos = res.iter().position(|item| item == &div_6).unwrap() as u32;
+
+    (div_2_pos + 1) * (div_6_pos + 1)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+    type Input = Vec<Pair>;
+
+    fn parse(input: &str) -> error::Result<Vec<Pair>> {
+        generator(input)
+    }
+
+    fn part1(input: &Vec<Pair>) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Vec<Pair>) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input =
+            fs::read_to_string("test_input/2022/day13.txt").expect("Error reading test input file");
+        assert_eq!(part_1(&generator(&input).unwrap()), Ok(13));
+    }
+
+    #[test]
+    #[allow(clippy::bool_assert_comparison)]
+    fn test_in_right_order() {
+        assert_eq!(
+            in_right_order(&from_str("[1,1,3,1,1]"), &from_str("[1,1,5,1,1]")),
+            true
+        );
+
+        assert_eq!(
+            in_right_order(&from_str("[[1],[2,3,4]]"), &from_str("[[1],4]")),
+            true
+        );
+
+        assert_eq!(
+            in_right_order(&from_str("[9]"), &from_str("[[8,7,6]]")),
+            false
+        );
+
+        assert_eq!(
+            in_right_order(&from_str("[[4,4],4,4]"), &from_str("[[4,4],4,4,4]")),
+            true
+        );
+
+        assert_eq!(
+            in_right_order(&from_str("[7,7,7,7]"), &from_str("[7,7,7]")),
+            false
+        );
+
+        assert_eq!(in_right_order(&from_str("[]"), &from_str("[3]")), true);
+
+        assert_eq!(
+            in_right_order(&from_str("[[[]]]"), &from_str("[[]]")),
+            false
+        );
+
+        assert_eq!(
+            in_right_order(
+                &from_str("[1,[2,[3,[4,[5,6,7]]]],8,9]"),
+                &from_str("[1,[2,[3,[4,[5,6,0]]]],8,9]")
+            ),
+            false
+        );
+    }
+
+    #[test]
+    fn test_compare() {
+        assert_eq!(compare(&from_str("[[1]]"), &from_str("[1]")), Equal);
+        assert_eq!(compare(&from_str("[]"), &from_str("[[]]")), Less);
+        let packets = parse_packets("[1,1]\n[1,2]\n\n[[6]]\n").unwrap();
+        assert_eq!(decoder_key(packets), 12);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input =
+            fs::read_to_string("test_input/2022/day13.txt").expect("Error reading test input file");
+        assert_eq!(part_2(&generator(&input).unwrap()), Ok(140));
+    }
+
+    #[test]
+    fn test_invalid_packet() {
+        assert_eq!(
+            generator("[1,1]\n[1,2]\n\n[1,[2]\n[3]\n"),
+            Err(Error::parse(13, 4, 6, "[1,[2]", "invalid packet"))
+        );
+        assert_eq!(
+            generator("[1,1]\n[1,\"a\"]\n"),
+            Err(Error::parse(
+                13,
+                2,
+                1,
+                "[1,\"a\"]",
+                "packets must be lists of lists and integers"
+            ))
+        );
+        assert_eq!(
+            generator("[1,1]\n[1,2]\n\n[3]\n"),
+            Err(Error::parse(
+                13,
+                4,
+                1,
+                "1 packets",
+                "expected a pair of packets"
+            ))
+        );
+    }
+}
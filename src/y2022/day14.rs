@@ -0,0 +1,389 @@
+use core::cmp::Ordering;
+use itertools::Itertools;
+use serde::{Deserialize, Serialize, Serializer};
+use std::{collections::HashSet, fmt, ops::RangeInclusive};
+use tracing::debug;
+
+use crate::{
+    error::{self, Error},
+    params,
+    solution::{Answer, Solution},
+    util::{
+        grid::{Grid, Pos},
+        parse::numbered_lines,
+    },
+    viz::{self, Cell, Frame, Render, Rgb},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Air,
+    Rock,
+    Sand,
+}
+
+impl fmt::Display for Tile {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let c = match self {
+            Tile::Air => '.',
+            Tile::Rock => '#',
+            Tile::Sand => 'o',
+        };
+        write!(f, "{c}")
+    }
+}
+
+/// The scanned slice of the cave, spanning the rocks horizontally and from the sand source down
+/// to the lowest rock. Anything outside it is air.
+pub type Cave = Grid<Tile>;
+
+/// Tiles are written as they are drawn, so a dumped cave reads like the puzzle.
+impl Serialize for Tile {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+#[derive(Serialize)]
+pub struct Input {
+    pub cave: Cave,
+    pub params: Params,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// The floor in part 2 runs from x = 0 to just before this.
+    pub floor_width: usize,
+    /// Units of sand to drop in part 2 before giving up.
+    pub iterations: usize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            floor_width: 1000,
+            iterations: 100000,
+        }
+    }
+}
+
+fn get_range(a: i32, b: i32) -> RangeInclusive<i32> {
+    match a.cmp(&b) {
+        Ordering::Greater => b..=a,
+        Ordering::Less => a..=b,
+        Ordering::Equal => a..=b,
+    }
+}
+
+#[aoc_generator(day14)]
+fn generator(input: &str) -> error::Result<Input> {
+    Ok(Input {
+        cave: parse_cave(input)?,
+        params: Params::default(),
+    })
+}
+
+fn parse_cave(input: &str) -> error::Result<Cave> {
+    let mut rocks = vec![];
+    for (line_no, line) in numbered_lines(input) {
+        let path = line
+            .split("->")
+            .map(|point| parse_point(line_no, line, point.trim()))
+            .collect::<error::Result<Vec<_>>>()?;
+        rocks.extend(path.into_iter().tuple_windows().flat_map(|(a, b)| {
+            if a.x == b.x {
+                let range = get_range(a.y, b.y);
+                return range.map(|y| Pos::new(a.x, y)).collect_vec();
+            }
+            get_range(a.x, b.x).map(|x| Pos::new(x, a.y)).collect_vec()
+        }));
+    }
+
+    let (Some(min_x), Some(max_x), Some(max_y)) = (
+        rocks.iter().map(|rock| rock.x).min(),
+        rocks.iter().map(|rock| rock.x).max(),
+        rocks.iter().map(|rock| rock.y).max(),
+    ) else {
+        return Err(Error::no_solution(Day14::DAY, "no rock paths in input"));
+    };
+    let width = (max_x - min_x + 1) as usize;
+    let mut cave = Grid::with_origin(Pos::new(min_x, 0), width, max_y as usize + 1, Tile::Air);
+    for rock in rocks {
+        cave.set(rock, Tile::Rock);
+    }
+    Ok(cave)
+}
+
+fn parse_point(line_no: usize, line: &str, point: &str) -> error::Result<Pos> {
+    let invalid = || {
+        Error::parse_token(
+            Day14::DAY,
+            line_no,
+            line,
+            point,
+            "expected a point like `498,4`",
+        )
+    };
+    let (x, y) = point.split_once(',').ok_or_else(invalid)?;
+    let (x, y) = (
+        x.parse().map_err(|_| invalid())?,
+        y.parse().map_err(|_| invalid())?,
+    );
+    if x < 0 || y < 0 {
+        return Err(invalid());
+    }
+    Ok(Pos::new(x, y))
+}
+
+fn is_free(input: &Cave, pos: Pos) -> bool {
+    input.get(pos).is_none_or(|&tile| tile == Tile::Air)
+}
+
+fn check_below(input: &Cave, pos: Pos) -> Option<Pos> {
+    [0, -1, 1]
+        .into_iter()
+        .map(|dx| pos + Pos::new(dx, 1))
+        .find(|&next| is_free(input, next))
+}
+
+#[derive(Debug, PartialEq)]
+enum State {
+    Stopped,
+    /// The sand came to rest on the source, so no more can enter.
+    Blocked,
+    Dropped,
+}
+
+const START_POS: Pos = Pos::new(500, 0);
+
+fn drop_sand(input: &mut Cave, max_height: i32) -> State {
+    let mut sand = START_POS;
+    while let Some(next_sand) = check_below(input, sand) {
+        if next_sand.y > max_height {
+            return State::Dropped;
+        }
+        sand = next_sand;
+    }
+    input.set(sand, Tile::Sand);
+    if sand == START_POS {
+        return State::Blocked;
+    }
+    State::Stopped
+}
+
+#[aoc(day14, part1)]
+fn part_1(input: &Input) -> error::Result<usize> {
+    let mut input = input.cave.to_owned();
+    let max_height = input.max().y;
+
+    let mut count = 0;
+    loop {
+        match drop_sand(&mut input, max_height) {
+            State::Stopped => count += 1,
+            State::Blocked => return Ok(count + 1),
+            State::Dropped => return Ok(count),
+        }
+    }
+}
+
+#[aoc(day14, part2)]
+fn part_2(input: &Input) -> error::Result<usize> {
+    let Params {
+        floor_width,
+        iterations,
+    } = input.params;
+    let floor = input.cave.max().y + 2;
+    let mut cave = Grid::new(floor_width, floor as usize + 1, Tile::Air);
+    for (pos, &tile) in input.cave.iter() {
+        if tile != Tile::Air && cave.set(pos, tile).is_none() {
+            return Err(Error::no_solution(
+                Day14::DAY,
+                format!("rock at {pos} is beyond the edge of the floor"),
+            ));
+        }
+    }
+    for x in 0..floor_width as i32 {
+        cave.set(Pos::new(x, floor), Tile::Rock);
+    }
+
+    for i in 1..iterations {
+        let mut sand = START_POS;
+        while let Some(next_sand) = check_below(&cave, sand) {
+            if next_sand.y > floor {
+                return Err(Error::no_solution(
+                    Day14::DAY,
+                    format!("sand fell off the edge of the floor at {next_sand}"),
+                ));
+            }
+            sand = next_sand;
+        }
+        if sand == START_POS {
+            debug!(units = i, "sand reached the source");
+            return Ok(i);
+        }
+        cave.set(sand, Tile::Sand);
+    }
+
+    Err(Error::no_solution(Day14::DAY, "not enough iterations"))
+}
+
+/// Part 1's sand coming to rest in the scanned slice of the cave, one frame per unit.
+impl Render for Input {
+    fn frames(&self) -> Vec<Frame> {
+        let to_frame = |cave: &Cave| {
+            let mut frame =
+                Grid::with_origin(cave.origin(), cave.width(), cave.height(), Cell::default());
+            for (pos, &tile) in cave.iter() {
+                let color = match tile {
+                    Tile::Air => Rgb(20, 20, 30),
+                    Tile::Rock => Rgb(130, 120, 110),
+                    Tile::Sand => Rgb(230, 200, 90),
+                };
+                frame.set(
+                    pos,
+                    Cell::new(tile.to_string().chars().next().unwrap(), color),
+                );
+            }
+            if cave.get(START_POS) == Some(&Tile::Air) {
+                frame.set(START_POS, Cell::new('+', Rgb(255, 60, 40)));
+            }
+            frame
+        };
+
+        let mut cave = self.cave.clone();
+        let max_height = cave.max().y;
+        let units = part_1(self).unwrap_or(0);
+        let shown: HashSet<usize> = viz::sample((0..=units).collect(), 200)
+            .into_iter()
+            .collect();
+        let mut frames = vec![to_frame(&cave)];
+        for unit in 1..=units {
+            drop_sand(&mut cave, max_height);
+            if shown.contains(&unit) {
+                frames.push(to_frame(&cave));
+            }
+        }
+        frames
+    }
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+    type Input = Input;
+
+    fn parse(input: &str) -> error::Result<Input> {
+        generator(input)
+    }
+
+    fn parse_with(input: &str, params: &params::Params) -> error::Result<Input> {
+        Ok(Input {
+            cave: parse_cave(input)?,
+            params: params.get(Self::DAY)?,
+        })
+    }
+
+    fn part1(input: &Input) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Input) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn render(input: &Input) -> Option<error::Result<Vec<Frame>>> {
+        Some(Ok(input.frames()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const SAMPLE: &str = "498,4 -> 498,6 -> 496,6
+503,4 -> 502,4 -> 502,9 -> 494,9";
+
+    #[test]
+    fn test_part_2() {
+        let input = generator(SAMPLE).unwrap();
+        assert_eq!(part_2(&input), Ok(93));
+    }
+
+    #[test]
+    fn test_part_1() {
+        let input = generator(SAMPLE).unwrap();
+        assert_eq!(part_1(&input), Ok(24));
+    }
+
+    #[test]
+    fn test_cave() {
+        let mut input = generator(SAMPLE).unwrap().cave;
+        assert_eq!(input.origin(), Pos::new(494, 0));
+        drop_sand(&mut input, 9);
+        assert_eq!(input.row(8).join(""), "......o.#.");
+    }
+
+    #[test]
+    fn test_render() {
+        let frames = generator(SAMPLE).unwrap().frames();
+        assert_eq!(frames.len(), 25);
+        assert_eq!(frames[0].row(0).join(""), "......+...");
+        assert_eq!(
+            frames[24].to_string(),
+            "......+...
+..........
+......o...
+.....ooo..
+....#ooo##
+...o#ooo#.
+..###ooo#.
+....oooo#.
+.o.ooooo#.
+#########."
+        );
+    }
+
+    #[test]
+    fn test_part_1_blocked_source() {
+        let input = generator("499,1 -> 499,2 -> 501,2 -> 501,1\n").unwrap();
+        assert_eq!(part_1(&input), Ok(2));
+    }
+
+    #[test]
+    fn test_params() {
+        let mut params = params::Params::default();
+        params.set("iterations", 50);
+        let input = Day14::parse_with(SAMPLE, &params).unwrap();
+        assert_eq!(
+            part_2(&input),
+            Err(Error::no_solution(14, "not enough iterations"))
+        );
+
+        params.set("floor_width", 505);
+        params.set("iterations", 100);
+        let input = Day14::parse_with(SAMPLE, &params).unwrap();
+        assert_eq!(
+            part_2(&input),
+            Err(Error::no_solution(
+                14,
+                "sand fell off the edge of the floor at (505, 12)"
+            ))
+        );
+    }
+
+    #[test]
+    fn test_invalid_path() {
+        assert_eq!(
+            generator("498,4 -> 498,6\n503,4 -> 502 4\n").err(),
+            Some(Error::parse(
+                14,
+                2,
+                10,
+                "502 4",
+                "expected a point like `498,4`"
+            ))
+        );
+    }
+}
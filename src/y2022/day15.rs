@@ -0,0 +1,384 @@
+use std::collections::HashSet;
+
+use itertools::Itertools;
+use rayon::prelude::*;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use tracing::debug;
+
+use crate::{
+    error::{self, Error},
+    params,
+    solution::{Alternate, Answer, Solution},
+    util::{
+        grid::{self, Grid},
+        intervals,
+        parse::numbered_lines,
+    },
+    viz::{Cell, Frame, Render, Rgb},
+};
+
+pub type Pos = (isize, isize);
+
+/// A sensor and the closest beacon to it. No other beacon is within `radius` of the sensor.
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct Sensor {
+    pos: Pos,
+    beacon: Pos,
+    dist: isize,
+}
+
+impl Sensor {
+    pub fn new(pos: Pos, beacon: Pos) -> Sensor {
+        Sensor {
+            pos,
+            beacon,
+            dist: distance(pos, beacon),
+        }
+    }
+
+    pub fn pos(&self) -> Pos {
+        self.pos
+    }
+
+    pub fn beacon(&self) -> Pos {
+        self.beacon
+    }
+
+    /// The Manhattan distance to the beacon.
+    pub fn radius(&self) -> isize {
+        self.dist
+    }
+}
+
+#[derive(Serialize)]
+pub struct Input {
+    pub sensors: Vec<Sensor>,
+    pub params: Params,
+}
+
+/// The sample asks about row 10 and a 20 by 20 search area.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct Params {
+    /// Row to count the positions without a beacon in, for part 1.
+    pub row: isize,
+    /// Both coordinates of the distress beacon are between 0 and this, for part 2.
+    pub bound: isize,
+}
+
+impl Default for Params {
+    fn default() -> Self {
+        Params {
+            row: 2000000,
+            bound: 4000000,
+        }
+    }
+}
+
+#[aoc_generator(day15)]
+fn generator(input: &str) -> error::Result<Input> {
+    Ok(Input {
+        sensors: parse_sensors(input)?,
+        params: Params::default(),
+    })
+}
+
+pub fn parse_sensors(input: &str) -> error::Result<Vec<Sensor>> {
+    let re =
+        Regex::new(r"^Sensor at x=(-?\d+), y=(-?\d+): closest beacon is at x=(-?\d+), y=(-?\d+)$")
+            .unwrap();
+    numbered_lines(input)
+        .map(|(line_no, line)| {
+            let line = line.trim();
+            let caps = re.captures(line).ok_or_else(|| {
+                Error::parse(
+                    Day15::DAY,
+                    line_no,
+                    1,
+                    line,
+                    "expected `Sensor at x=.., y=..: closest beacon is at x=.., y=..`",
+                )
+            })?;
+            let coord = |i: usize| -> error::Result<isize> {
+                let cap = caps.get(i).unwrap();
+                cap.as_str().parse().map_err(|_| {
+                    Error::parse(
+                        Day15::DAY,
+                        line_no,
+                        cap.start() + 1,
+                        cap.as_str(),
+                        "coordinate out of range",
+                    )
+                })
+            };
+            Ok(Sensor::new((coord(1)?, coord(2)?), (coord(3)?, coord(4)?)))
+        })
+        .collect()
+}
+
+#[aoc(day15, part1)]
+fn part_1(input: &Input) -> error::Result<usize> {
+    Ok(positions_without_beacon(&input.sensors, input.params.row))
+}
+
+fn distance((p1, p2): (isize, isize), (q1, q2): (isize, isize)) -> isize {
+    (p1 - q1).abs() + (p2 - q2).abs()
+}
+
+/// How many positions in `row` can't have a beacon, because a sensor has a closer one.
+pub fn positions_without_beacon(input: &[Sensor], row: isize) -> usize {
+    let known_beacons: HashSet<Pos> = input.iter().map(|sensor| sensor.beacon).collect();
+    input
+        .iter()
+        .flat_map(|sensor| {
+            let mut points = vec![];
+            let (sx, sy) = sensor.pos;
+            for x in (sx - sensor.dist)..(sx + sensor.dist) {
+                if distance((x, row), (sx, sy)) <= sensor.dist && !known_beacons.contains(&(x, row))
+                {
+                    points.push((x, row));
+                }
+            }
+            points
+        })
+        .unique()
+        .count()
+}
+
+#[aoc(day15, part2)]
+fn part_2(input: &Input) -> error::Result<isize> {
+    solve_part_2(&input.sensors, input.params.bound)
+}
+
+#[aoc(day15, part2, rayon)]
+fn part_2_rayon(input: &Input) -> error::Result<isize> {
+    solve_part_2_rayon(&input.sensors, input.params.bound)
+}
+
+fn find_ranges(x: isize, sensors: &[Sensor], max: isize) -> Vec<(isize, isize)> {
+    sensors
+        .iter()
+        .filter_map(|sensor| {
+            let d = sensor.dist;
+            let f = d - (sensor.pos.0 - x).abs();
+            if f < 0 {
+                None
+            } else {
+                Some(((sensor.pos.1 - f).max(0), (sensor.pos.1 + f).min(max)))
+            }
+        })
+        .sorted_by(|a, b| a.0.cmp(&b.0))
+        .collect_vec()
+}
+
+/// The first column no range covers, or `None` if the ranges leave no gap.
+fn find_range_gap(ranges: &[(isize, isize)]) -> Option<isize> {
+    // no sensor reaches this column at all
+    if ranges.is_empty() {
+        return Some(0);
+    }
+    let merged = intervals::merge(ranges.iter().copied());
+    let gap = merged.get(1).map(|range| range.0 - 1);
+    if gap.is_some() {
+        debug!(covered = ?merged[0], range = ?merged[1], "found gap");
+    }
+    gap
+}
+
+/// The only position with both coordinates between 0 and `max` that no sensor covers.
+pub fn find_beacon(input: &[Sensor], max: isize) -> Option<Pos> {
+    (0..=max).find_map(|x| {
+        let ranges = find_ranges(x, input, max);
+        find_range_gap(&ranges).map(|y| (x, y))
+    })
+}
+
+/// The distress beacon's tuning frequency.
+pub fn tuning_frequency((x, y): Pos) -> isize {
+    x * 4000000 + y
+}
+
+fn solve_part_2(input: &[Sensor], max: isize) -> error::Result<isize> {
+    find_beacon(input, max)
+        .map(tuning_frequency)
+        .ok_or_else(|| {
+            Error::no_solution(
+                Day15::DAY,
+                format!("answer not found after {max} iterations"),
+            )
+        })
+}
+
+/// The same as [`solve_part_2`], with the columns scanned in parallel. The first column with a
+/// gap still wins, so both agree even if there were several.
+fn solve_part_2_rayon(input: &[Sensor], max: isize) -> error::Result<isize> {
+    (0..=max)
+        .into_par_iter()
+        .find_map_first(|x| {
+            let ranges = find_ranges(x, input, max);
+            find_range_gap(&ranges).map(|y| tuning_frequency((x, y)))
+        })
+        .ok_or_else(|| {
+            Error::no_solution(
+                Day15::DAY,
+                format!("answer not found after {max} iterations"),
+            )
+        })
+}
+
+/// The search area shrunk to at most 200 cells a side, shaded by how many sensors cover each
+/// cell, with the distress beacon marked.
+impl Render for Input {
+    fn frames(&self) -> Vec<Frame> {
+        let max = self.params.bound.max(0);
+        let step = (max + 1 + 199) / 200;
+        let side = ((max + 1 + step - 1) / step) as usize;
+        let cell_of = |(x, y): Pos| {
+            let cell = |c: isize| i32::try_from(c.div_euclid(step)).ok();
+            Some(grid::Pos::new(cell(x)?, cell(y)?))
+        };
+
+        let mut frame = Grid::new(side, side, Cell::default());
+        for pos in frame.positions().collect_vec() {
+            let point = (pos.x as isize * step, pos.y as isize * step);
+            let covering = self
+                .sensors
+                .iter()
+                .filter(|sensor| distance(sensor.pos, point) <= sensor.dist)
+                .count();
+            let cell = match covering {
+                0 => Cell::new('.', Rgb::BLACK),
+                n => Cell::new('#', Rgb(20, 40, 90).mix(Rgb(120, 180, 255), n as f32 / 4.0)),
+            };
+            frame.set(pos, cell);
+        }
+        for sensor in &self.sensors {
+            if let Some(pos) = cell_of(sensor.beacon) {
+                frame.set(pos, Cell::new('B', Rgb(250, 250, 120)));
+            }
+            if let Some(pos) = cell_of(sensor.pos) {
+                frame.set(pos, Cell::new('S', Rgb::WHITE));
+            }
+        }
+        if let Some(pos) = find_beacon(&self.sensors, max).and_then(cell_of) {
+            frame.set(pos, Cell::new('*', Rgb(255, 60, 40)));
+        }
+        vec![frame]
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+    type Input = Input;
+
+    fn parse(input: &str) -> error::Result<Input> {
+        generator(input)
+    }
+
+    fn parse_with(input: &str, params: &params::Params) -> error::Result<Input> {
+        Ok(Input {
+            sensors: parse_sensors(input)?,
+            params: params.get(Self::DAY)?,
+        })
+    }
+
+    fn part1(input: &Input) -> error::Result<Answer> {
+        part_1(input).map(Answer::from)
+    }
+
+    fn part2(input: &Input) -> error::Result<Answer> {
+        part_2(input).map(Answer::from)
+    }
+
+    fn render(input: &Input) -> Option<error::Result<Vec<Frame>>> {
+        Some(Ok(input.frames()))
+    }
+
+    fn alternates() -> Vec<Alternate<Input>> {
+        vec![Alternate {
+            part: 2,
+            name: "rayon",
+            run: |input| part_2_rayon(input).map(Answer::from),
+        }]
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+
+    use super::*;
+
+    #[test]
+    fn test_part_1() {
+        let input =
+            fs::read_to_string("test_input/2022/day15.txt").expect("Error reading test input file");
+        let input = parse_sensors(&input).unwrap();
+        assert_eq!(positions_without_beacon(&input, 10), 26);
+    }
+
+    #[test]
+    fn test_part_2() {
+        let input =
+            fs::read_to_string("test_input/2022/day15.txt").expect("Error reading test input file");
+        let input = parse_sensors(&input).unwrap();
+        assert_eq!(find_beacon(&input, 20), Some((14, 11)));
+        assert_eq!(solve_part_2(&input, 20), Ok(56000011));
+        assert_eq!(solve_part_2_rayon(&input, 20), Ok(56000011));
+    }
+
+    #[test]
+    fn test_params() {
+        let input =
+            fs::read_to_string("test_input/2022/day15.txt").expect("Error reading test input file");
+        let params = params::Params::load("test_input/2022/day15.params.json".as_ref()).unwrap();
+        let input = Day15::parse_with(&input, &params).unwrap();
+        assert_eq!(part_1(&input), Ok(26));
+        assert_eq!(part_2(&input), Ok(56000011));
+        assert_eq!(part_2_rayon(&input), Ok(56000011));
+    }
+
+    #[test]
+    fn test_render() {
+        let input =
+            fs::read_to_string("test_input/2022/day15.txt").expect("Error reading test input file");
+        let params = params::Params::load("test_input/2022/day15.params.json".as_ref()).unwrap();
+        let frames = Day15::parse_with(&input, &params).unwrap().frames();
+        assert_eq!(frames.len(), 1);
+        let frame = &frames[0];
+        assert_eq!((frame.width(), frame.height()), (21, 21));
+        assert_eq!(frame.get((14, 11).into()).unwrap().c, '*');
+        assert_eq!(frame.get((2, 18).into()).unwrap().c, 'S');
+        assert_eq!(frame.get((15, 3).into()).unwrap().c, 'B');
+        assert!(frame.iter().all(|(_, cell)| cell.c != '.'));
+    }
+
+    #[test]
+    fn test_find_ranges() {
+        let input =
+            parse_sensors("Sensor at x=10, y=10: closest beacon is at x=13, y=10\n").unwrap();
+        assert_eq!(find_ranges(12, &input, 20), vec![(9, 11)]);
+        assert_eq!(find_ranges(13, &input, 20), vec![(10, 10)]);
+        // further away than the beacon, but within twice the distance
+        assert_eq!(find_ranges(15, &input, 20), vec![]);
+    }
+
+    #[test]
+    fn test_invalid_sensor() {
+        let input = "Sensor at x=2, y=18: closest beacon is at x=-2, y=15
+Sensor at x=9, y=16: closest beacon is at x=10, y=16a";
+        assert_eq!(
+            generator(input).err(),
+            Some(Error::parse(
+                15,
+                2,
+                1,
+                "Sensor at x=9, y=16: closest beacon is at x=10, y=16a",
+                "expected `Sensor at x=.., y=..: closest beacon is at x=.., y=..`"
+            ))
+        );
+    }
+}
@@ -0,0 +1,548 @@
+//! Generators for the 2022 puzzle inputs.
+
+use std::collections::BTreeSet;
+
+use itertools::Itertools;
+use rand::{seq::SliceRandom, Rng, SeedableRng};
+use rand_chacha::ChaCha8Rng;
+
+use crate::{gen::Size, params::Params};
+
+/// A random but valid input for `day`, or `None` if there is no generator for it.
+pub fn generate(day: u8, seed: u64, size: Size) -> Option<String> {
+    let rng = &mut ChaCha8Rng::seed_from_u64(seed);
+    let count = size.count.max(1);
+    let input = match day {
+        1 => calories(rng, count),
+        2 => strategy_guide(rng, count),
+        3 => rucksacks(rng, count),
+        4 => section_pairs(rng, count),
+        5 => crates(rng, count),
+        6 => datastream(rng, count),
+        7 => terminal(rng, count),
+        8 => trees(rng, size.width.max(1), size.height.max(1)),
+        9 => motions(rng, count),
+        10 => program(rng, count),
+        11 => monkey_notes(rng, count),
+        12 => heightmap(rng, size.width.max(26), size.height.max(1)),
+        13 => packet_pairs(rng, count),
+        14 => rock_paths(rng, count),
+        15 => sensors(rng, count, size.area as i64),
+        16 => valves(rng, count),
+        _ => return None,
+    };
+    Some(input)
+}
+
+fn calories(rng: &mut impl Rng, elves: usize) -> String {
+    (0..elves)
+        .map(|_| {
+            (0..rng.gen_range(1..=8))
+                .map(|_| rng.gen_range(1000..=60000).to_string())
+                .join("\n")
+        })
+        .join("\n\n")
+        + "\n"
+}
+
+fn strategy_guide(rng: &mut impl Rng, rounds: usize) -> String {
+    (0..rounds)
+        .map(|_| {
+            let elf = *b"ABC".choose(rng).unwrap() as char;
+            let me = *b"XYZ".choose(rng).unwrap() as char;
+            format!("{elf} {me}\n")
+        })
+        .collect()
+}
+
+/// Groups of three rucksacks sharing exactly one badge, where each rucksack has exactly one item
+/// in both compartments.
+fn rucksacks(rng: &mut impl Rng, groups: usize) -> String {
+    let items = ('a'..='z').chain('A'..='Z').collect_vec();
+    let mut input = String::new();
+    for _ in 0..groups {
+        let mut items = items.clone();
+        items.shuffle(rng);
+        let badge = items[0];
+        // 17 items each, so no other item can be shared by the whole group
+        for own in items[1..].chunks(17) {
+            let (common, pools) = own.split_first().unwrap();
+            let (left_pool, right_pool) = pools.split_at(8);
+            let extra = rng.gen_range(0..left_pool.len());
+
+            let mut left = vec![badge, *common];
+            left.extend(left_pool.choose_multiple(rng, extra));
+            let mut right = vec![*common];
+            right.extend(right_pool.choose_multiple(rng, extra + 1));
+            left.shuffle(rng);
+            right.shuffle(rng);
+            input.extend(left.into_iter().chain(right));
+            input.push('\n');
+        }
+    }
+    input
+}
+
+fn section_pairs(rng: &mut impl Rng, pairs: usize) -> String {
+    (0..pairs)
+        .map(|_| format!("{},{}\n", sections(rng), sections(rng)))
+        .collect()
+}
+
+fn sections(rng: &mut impl Rng) -> String {
+    let from = rng.gen_range(1..=99);
+    let to = rng.gen_range(from..=99);
+    format!("{from}-{to}")
+}
+
+/// Nine stacks drawn eight crates high, as day 5 expects, followed by `moves` moves that never
+/// take the last crate off a stack.
+fn crates(rng: &mut impl Rng, moves: usize) -> String {
+    const STACKS: usize = 9;
+    const HEIGHT: usize = 8;
+
+    let mut heights = (0..STACKS).map(|_| rng.gen_range(1..=HEIGHT)).collect_vec();
+    // a full stack keeps the top row from being blank, which would end the drawing early
+    heights[rng.gen_range(0..STACKS)] = HEIGHT;
+
+    let mut input = String::new();
+    for row in (0..HEIGHT).rev() {
+        let line = heights
+            .iter()
+            .map(|&height| {
+                if row < height {
+                    format!("[{}]", rng.gen_range('A'..='Z'))
+                } else {
+                    "   ".to_string()
+                }
+            })
+            .join(" ");
+        input += line.trim_end();
+        input.push('\n');
+    }
+    input += &(1..=STACKS).map(|stack| format!(" {stack} ")).join(" ");
+    input += "\n\n";
+
+    for _ in 0..moves {
+        let from = *(0..STACKS)
+            .filter(|&stack| heights[stack] > 1)
+            .collect_vec()
+            .choose(rng)
+            .unwrap();
+        let to = (from + rng.gen_range(1..STACKS)) % STACKS;
+        let count = rng.gen_range(1..heights[from]);
+        heights[from] -= count;
+        heights[to] += count;
+        input += &format!("move {count} from {} to {}\n", from + 1, to + 1);
+    }
+    input
+}
+
+/// Noise drawn from three letters, which can't contain a marker, then 14 distinct letters.
+fn datastream(rng: &mut impl Rng, len: usize) -> String {
+    let mut input = (0..len)
+        .map(|_| rng.gen_range('a'..='c'))
+        .collect::<String>();
+    let mut marker = ('d'..='z').collect_vec();
+    marker.shuffle(rng);
+    input.extend(&marker[..14]);
+    input.extend((0..len / 4).map(|_| rng.gen_range('a'..='z')));
+    input + "\n"
+}
+
+struct Dir {
+    name: String,
+    children: Vec<usize>,
+    files: Vec<(u32, String)>,
+}
+
+/// A terminal session exploring a random tree of `dirs` directories, using more than 40000000
+/// bytes so part 2 has something to free.
+fn terminal(rng: &mut impl Rng, dirs: usize) -> String {
+    let mut tree = vec![Dir {
+        name: "/".to_string(),
+        children: vec![],
+        files: vec![],
+    }];
+    for i in 1..dirs {
+        let parent = rng.gen_range(0..i);
+        let taken = tree[parent]
+            .children
+            .iter()
+            .map(|&child| tree[child].name.clone())
+            .collect::<BTreeSet<_>>();
+        let name = unique_name(rng, &taken);
+        tree[parent].children.push(i);
+        tree.push(Dir {
+            name,
+            children: vec![],
+            files: vec![],
+        });
+    }
+
+    let mut total = 0;
+    for i in 0..tree.len() {
+        for _ in 0..rng.gen_range(0..=5) {
+            let taken = tree[i]
+                .files
+                .iter()
+                .map(|(_, name)| name.clone())
+                .chain(
+                    tree[i]
+                        .children
+                        .iter()
+                        .map(|&child| tree[child].name.clone()),
+                )
+                .collect::<BTreeSet<_>>();
+            let name = loop {
+                let name = unique_name(rng, &taken) + "." + &unique_name(rng, &BTreeSet::new());
+                if !taken.contains(&name) {
+                    break name;
+                }
+            };
+            let size = rng.gen_range(1000..=300_000);
+            total += size;
+            tree[i].files.push((size, name));
+        }
+    }
+    if total <= 40_000_000 {
+        let dir = rng.gen_range(0..tree.len());
+        let size = 40_000_000 - total + rng.gen_range(1..=5_000_000);
+        tree[dir].files.push((size, "big.bin".to_string()));
+    }
+
+    let mut input = "$ cd /\n".to_string();
+    list_dir(rng, &tree, 0, &mut input);
+    input
+}
+
+fn unique_name(rng: &mut impl Rng, taken: &BTreeSet<String>) -> String {
+    loop {
+        let name = (0..rng.gen_range(1..=8))
+            .map(|_| rng.gen_range('a'..='z'))
+            .collect::<String>();
+        if !taken.contains(&name) {
+            return name;
+        }
+    }
+}
+
+fn list_dir(rng: &mut impl Rng, tree: &[Dir], dir: usize, input: &mut String) {
+    let dir = &tree[dir];
+    let mut listing = dir
+        .children
+        .iter()
+        .map(|&child| format!("dir {}", tree[child].name))
+        .chain(
+            dir.files
+                .iter()
+                .map(|(size, name)| format!("{size} {name}")),
+        )
+        .collect_vec();
+    listing.shuffle(rng);
+    *input += "$ ls\n";
+    for line in listing {
+        *input += &line;
+        input.push('\n');
+    }
+    for &child in &dir.children {
+        *input += &format!("$ cd {}\n", tree[child].name);
+        list_dir(rng, tree, child, input);
+        *input += "$ cd ..\n";
+    }
+}
+
+fn trees(rng: &mut impl Rng, width: usize, height: usize) -> String {
+    (0..height)
+        .map(|_| {
+            (0..width)
+                .map(|_| rng.gen_range('0'..='9'))
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+fn motions(rng: &mut impl Rng, motions: usize) -> String {
+    (0..motions)
+        .map(|_| {
+            let direction = *b"UDLR".choose(rng).unwrap() as char;
+            format!("{direction} {}\n", rng.gen_range(1..=20))
+        })
+        .collect()
+}
+
+/// At least `instructions` `noop` and `addx` instructions, and enough to draw all 240 pixels,
+/// keeping the sprite roughly on the screen.
+fn program(rng: &mut impl Rng, instructions: usize) -> String {
+    let mut input = String::new();
+    let (mut x, mut cycles) = (1, 0);
+    for i in 0.. {
+        if i >= instructions && cycles >= 240 {
+            break;
+        }
+        if rng.gen_bool(0.3) {
+            input += "noop\n";
+            cycles += 1;
+            continue;
+        }
+        let mut value = rng.gen_range(-10..=10);
+        if !(-5..=45).contains(&(x + value)) {
+            value = -value;
+        }
+        x += value;
+        cycles += 2;
+        input += &format!("addx {value}\n");
+    }
+    input
+}
+
+const PRIMES: [u32; 16] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53];
+
+/// Notes on up to 16 monkeys, each testing a different prime so the worry levels stay bounded.
+fn monkey_notes(rng: &mut impl Rng, monkeys: usize) -> String {
+    let monkeys = monkeys.clamp(2, PRIMES.len());
+    let mut primes = PRIMES;
+    primes.shuffle(rng);
+    (0..monkeys)
+        .map(|monkey| {
+            let items = (0..rng.gen_range(1..=6))
+                .map(|_| rng.gen_range(40..=99).to_string())
+                .join(", ");
+            let operation = match rng.gen_range(0..5) {
+                0 => "old * old".to_string(),
+                1 | 2 => format!("old * {}", rng.gen_range(2..=19)),
+                _ => format!("old + {}", rng.gen_range(1..=8)),
+            };
+            let mut other = || (monkey + rng.gen_range(1..monkeys)) % monkeys;
+            let (if_true, if_false) = (other(), other());
+            format!(
+                "Monkey {monkey}:\n  Starting items: {items}\n  Operation: new = {operation}\n  \
+                 Test: divisible by {}\n    If true: throw to monkey {if_true}\n    \
+                 If false: throw to monkey {if_false}\n",
+                primes[monkey]
+            )
+        })
+        .join("\n")
+}
+
+/// Elevation climbs steadily from `a` to `z` along the even rows, with `S` and `E` at either end
+/// of one of them. Odd rows are random.
+fn heightmap(rng: &mut impl Rng, width: usize, height: usize) -> String {
+    let row = 2 * rng.gen_range(0..height.div_ceil(2));
+    (0..height)
+        .map(|y| {
+            (0..width)
+                .map(|x| {
+                    if y % 2 == 1 {
+                        rng.gen_range('a'..='z')
+                    } else if (x, y) == (0, row) {
+                        'S'
+                    } else if (x, y) == (width - 1, row) {
+                        'E'
+                    } else {
+                        (b'a' + (x * 25 / (width - 1)) as u8) as char
+                    }
+                })
+                .collect::<String>()
+                + "\n"
+        })
+        .collect()
+}
+
+fn packet(rng: &mut impl Rng, depth: usize) -> String {
+    if depth > 0 && (depth >= 4 || rng.gen_bool(0.6)) {
+        return rng.gen_range(0..=10).to_string();
+    }
+    let items = (0..rng.gen_range(0..=4))
+        .map(|_| packet(rng, depth + 1))
+        .join(",");
+    format!("[{items}]")
+}
+
+fn packet_pairs(rng: &mut impl Rng, pairs: usize) -> String {
+    (0..pairs)
+        .map(|_| format!("{}\n{}\n", packet(rng, 0), packet(rng, 0)))
+        .join("\n")
+}
+
+/// Rock paths of straight segments, no deeper than 150 so part 2's sand fits on its floor.
+fn rock_paths(rng: &mut impl Rng, paths: usize) -> String {
+    let (xs, ys) = (440..=560, 1..=150);
+    (0..paths)
+        .map(|_| {
+            let (mut x, mut y) = (rng.gen_range(xs.clone()), rng.gen_range(ys.clone()));
+            let mut points = vec![format!("{x},{y}")];
+            for i in 0..rng.gen_range(1..=4) {
+                let step = rng.gen_range(1..=8) * sign(rng) as i32;
+                if i % 2 == 0 {
+                    x = (x + step).clamp(*xs.start(), *xs.end());
+                } else {
+                    y = (y + step).clamp(*ys.start(), *ys.end());
+                }
+                points.push(format!("{x},{y}"));
+            }
+            points.join(" -> ") + "\n"
+        })
+        .collect()
+}
+
+/// The parameters an input for `day` needs: day 15's row and search area follow `size.area`.
+pub fn params(day: u8, size: Size) -> Params {
+    let mut params = Params::default();
+    if day == 15 {
+        params.set("row", size.area / 2);
+        params.set("bound", size.area);
+    }
+    params
+}
+
+/// Sensors on a lattice covering the whole search area except one hidden spot. Every point
+/// other than the hidden one is covered by a corner of its lattice cell that lies beyond it as
+/// seen from the hidden spot, so shrinking each sensor's range to stop short of the hidden spot
+/// leaves no other gaps.
+fn sensors(rng: &mut impl Rng, sensors: usize, area: i64) -> String {
+    let side = ((sensors as f64).sqrt().ceil() as i64).max(2);
+    // at least two apart, so there are points between the sensors to hide the beacon at
+    let area = area.max(2 * side);
+    let spacing = (area + side - 2) / (side - 1);
+    let mut off_lattice = || loop {
+        let value = rng.gen_range(1..area);
+        if value % spacing != 0 {
+            return value;
+        }
+    };
+    let hidden = (off_lattice(), off_lattice());
+
+    (0..side)
+        .cartesian_product(0..side)
+        .map(|(i, j)| {
+            let sensor = (i * spacing, j * spacing);
+            let to_hidden = (sensor.0 - hidden.0).abs() + (sensor.1 - hidden.1).abs();
+            let range = (2 * spacing).min(to_hidden - 1);
+            let dx = rng.gen_range(0..=range);
+            let (sx, sy) = (sign(rng), sign(rng));
+            let beacon = (sensor.0 + sx * dx, sensor.1 + sy * (range - dx));
+            format!(
+                "Sensor at x={}, y={}: closest beacon is at x={}, y={}\n",
+                sensor.0, sensor.1, beacon.0, beacon.1
+            )
+        })
+        .collect()
+}
+
+fn sign(rng: &mut impl Rng) -> i64 {
+    if rng.gen() {
+        1
+    } else {
+        -1
+    }
+}
+
+/// A connected tunnel network starting at `AA`, where about a third of the valves, at most 15,
+/// have a flow rate.
+fn valves(rng: &mut impl Rng, valves: usize) -> String {
+    let valves = valves.clamp(2, 26 * 26);
+    let mut names = vec!["AA".to_string()];
+    let mut taken = BTreeSet::from(["AA".to_string()]);
+    while names.len() < valves {
+        let name = (0..2).map(|_| rng.gen_range('A'..='Z')).collect::<String>();
+        if taken.insert(name.clone()) {
+            names.push(name);
+        }
+    }
+
+    let mut tunnels = vec![BTreeSet::new(); valves];
+    let mut connect = |a: usize, b: usize| {
+        if a != b {
+            tunnels[a].insert(b);
+            tunnels[b].insert(a);
+        }
+    };
+    for valve in 1..valves {
+        connect(valve, rng.gen_range(0..valve));
+    }
+    for _ in 0..valves / 2 {
+        connect(rng.gen_range(0..valves), rng.gen_range(0..valves));
+    }
+
+    let mut rates = vec![0; valves];
+    let mut flowing = (1..valves).collect_vec();
+    flowing.shuffle(rng);
+    for valve in flowing.into_iter().take((valves / 3).clamp(1, 15)) {
+        rates[valve] = rng.gen_range(1..=25);
+    }
+
+    let mut lines = (0..valves)
+        .map(|valve| {
+            let leads = tunnels[valve].iter().map(|&to| &names[to]).join(", ");
+            let tunnels = if tunnels[valve].len() == 1 {
+                "tunnel leads to valve"
+            } else {
+                "tunnels lead to valves"
+            };
+            format!(
+                "Valve {} has flow rate={}; {tunnels} {leads}\n",
+                names[valve], rates[valve]
+            )
+        })
+        .collect_vec();
+    lines.shuffle(rng);
+    lines.concat()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::y2022::solutions;
+
+    fn small() -> Size {
+        Size {
+            count: 12,
+            width: 30,
+            height: 9,
+            area: 100,
+        }
+    }
+
+    #[test]
+    fn test_deterministic() {
+        for day in 1..=16 {
+            assert_eq!(generate(day, 7, small()), generate(day, 7, small()));
+            assert_ne!(generate(day, 7, small()), generate(day, 8, small()));
+        }
+        assert_eq!(generate(26, 7, small()), None);
+    }
+
+    #[test]
+    fn test_generated_inputs_solve() {
+        for (day, solution) in solutions() {
+            // a freshly scaffolded day has nothing to generate yet
+            if generate(day, 0, small()).is_none() {
+                continue;
+            }
+            for seed in 0..5 {
+                let input = generate(day, seed, small()).unwrap();
+                for (part, name) in solution.variants() {
+                    // day 16 part 2 isn't solved yet
+                    if (day, part) == (16, 2) {
+                        continue;
+                    }
+                    let parsed = solution
+                        .parse(&input, name, &params(day, small()))
+                        .unwrap_or_else(|err| panic!("seed {seed}: {err}\n{input}"));
+                    if let Err(err) = solution.solve(&*parsed, part, name) {
+                        panic!("day {day} part {part} seed {seed}: {err}\n{input}");
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_rucksacks() {
+        let input = generate(3, 1, small()).unwrap();
+        assert_eq!(input.lines().count(), 36);
+        assert!(input.lines().all(|line| line.len() % 2 == 0));
+    }
+}
@@ -0,0 +1,45 @@
+//! Advent of Code 2022.
+
+pub mod day01;
+pub mod day02;
+pub mod day03;
+pub mod day04;
+pub mod day05;
+pub mod day06;
+pub mod day07;
+pub mod day08;
+pub mod day09;
+pub mod day10;
+pub mod day11;
+pub mod day12;
+pub mod day13;
+pub mod day14;
+pub mod day15;
+pub mod day16;
+pub mod gen;
+
+use crate::solution::{register, DynSolution};
+
+pub const YEAR: u16 = 2022;
+
+/// Every implemented day, for [`crate::solution::registry`].
+pub fn solutions() -> Vec<(u8, Box<dyn DynSolution>)> {
+    vec![
+        register::<day01::Day01>(),
+        register::<day02::Day02>(),
+        register::<day03::Day03>(),
+        register::<day04::Day04>(),
+        register::<day05::Day05>(),
+        register::<day06::Day06>(),
+        register::<day07::Day07>(),
+        register::<day08::Day08>(),
+        register::<day09::Day09>(),
+        register::<day10::Day10>(),
+        register::<day11::Day11>(),
+        register::<day12::Day12>(),
+        register::<day13::Day13>(),
+        register::<day14::Day14>(),
+        register::<day15::Day15>(),
+        register::<day16::Day16>(),
+    ]
+}
@@ -0,0 +1,74 @@
+//! The public API, used the way another crate would.
+
+use advent_2022::{
+    util::{grid::Dir, intervals},
+    y2022::{day09, day11, day13, day15, day16},
+};
+
+#[test]
+fn test_rope() {
+    let motions = day09::parse_motions("R 4\nU 4\nL 3\nD 1\nR 4\nD 1\nL 5\nR 2\n").unwrap();
+    assert_eq!(day09::tail_positions(&motions, 2), 13);
+    assert_eq!(day09::tail_positions(&motions, 10), 1);
+
+    let mut rope = day09::Rope::new(2);
+    rope.step(Dir::Right);
+    rope.step(Dir::Right);
+    assert_eq!(rope.tail(), day09::resolve_tail(rope.head(), rope.tail()));
+}
+
+#[test]
+fn test_monkey_business() {
+    let monkeys = vec![
+        day11::Monkey::new(vec![79, 98], day11::Operation::Multiply(19), 23, 2, 3),
+        day11::Monkey::new(vec![54, 65, 75, 74], day11::Operation::Add(6), 19, 2, 0),
+        day11::Monkey::new(vec![79, 60, 97], day11::Operation::Square, 13, 1, 3),
+        day11::Monkey::new(vec![74], day11::Operation::Add(3), 17, 0, 1),
+    ];
+    assert_eq!(
+        day11::monkey_business(monkeys.clone(), 20, Some(3)),
+        Ok(10605)
+    );
+    assert_eq!(
+        day11::monkey_business(monkeys, 10_000, None),
+        Ok(2713310158)
+    );
+}
+
+#[test]
+fn test_packets() {
+    let packets =
+        day13::parse_packets("[1,1,3,1,1]\n[1,1,5,1,1]\n\n[[1],[2,3,4]]\n[[1],4]\n").unwrap();
+    assert!(day13::in_right_order(&packets[0], &packets[1]));
+    assert_eq!(
+        day13::compare(&packets[2], &packets[3]),
+        std::cmp::Ordering::Less
+    );
+}
+
+#[test]
+fn test_sensors() {
+    let sensors = day15::parse_sensors(
+        "Sensor at x=8, y=7: closest beacon is at x=2, y=10\n\
+         Sensor at x=0, y=11: closest beacon is at x=2, y=10\n",
+    )
+    .unwrap();
+    assert_eq!(sensors[0].radius(), 9);
+    assert_eq!(day15::positions_without_beacon(&sensors, 10), 16);
+    assert_eq!(
+        intervals::merge([(-1, 17), (0, 3), (19, 20)]),
+        vec![(-1, 17), (19, 20)]
+    );
+}
+
+#[test]
+fn test_valves() {
+    let valves = day16::parse_valves(
+        "Valve AA has flow rate=0; tunnels lead to valves BB\n\
+         Valve BB has flow rate=13; tunnels lead to valves AA\n",
+    )
+    .unwrap();
+    let compressed = day16::compress(&valves).unwrap();
+    assert_eq!(compressed["AA"].distances["BB"], 1);
+    assert_eq!(day16::release_pressure(&compressed, "AA", 0), Ok(0));
+}